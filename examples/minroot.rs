@@ -1,11 +1,26 @@
 //! Demonstrates how to use Nova to produce a recursive proof of the correct execution of
 //! iterations of the `MinRoot` function, thereby realizing a Nova-based verifiable delay function (VDF).
 //! We execute a configurable number of iterations of the `MinRoot` function per step of Nova's recursion.
+//!
+//! The MinRoot math itself (the `(p-3)/5` exponent used to take fifth roots) is field-agnostic, so the
+//! whole pipeline below is generic over the engine cycle (`E1`/`E2`) and the compressed SNARK
+//! backend (`S1`/`S2`). `run_minroot_demo` runs the same circuit for whatever cycle and SNARK
+//! backend it's instantiated with, and reports constraint counts and proving/verification times.
+//!
+//! The fifth root taken at each MinRoot iteration is supplied as non-deterministic advice at
+//! `prove_step` time rather than baked into `MinRootCircuit` up front; the circuit only checks it.
+//!
+//! This snapshot carries a real preprocessing SNARK (`spartan::ppsnark`) and a real pairing-based
+//! evaluation engine (`provider::hyperkzg`), but no concrete curve-cycle `Engine` implementation
+//! (`pallas`/`vesta`/`bn256_grumpkin`/`secp_secq` and a `DlogGroup`/`PairingDlogGroup` backing
+//! them) -- those are a large, separate undertaking this tree doesn't include yet. `main` can
+//! therefore only report that gap rather than run the pipeline below end to end; once a concrete
+//! `Engine` lands, instantiate `run_minroot_demo` with it exactly as sketched in the comment at
+//! the bottom of this file.
 use bellpepper_core::{num::AllocatedNum, ConstraintSystem, SynthesisError};
 use ff::{Field, PrimeField};
 use flate2::{write::ZlibEncoder, Compression};
 use nova_snark::{
-  provider::{PallasEngine, VestaEngine},
   traits::{
     circuit::{StepCircuit, TrivialCircuit},
     snark::RelaxedR1CSSNARKTrait,
@@ -16,13 +31,6 @@ use nova_snark::{
 use num_bigint::BigUint;
 use std::time::Instant;
 
-type E1 = VestaEngine;
-type E2 = PallasEngine;
-type EE1 = nova_snark::provider::ipa_pc::EvaluationEngine<E1>;
-type EE2 = nova_snark::provider::ipa_pc::EvaluationEngine<E2>;
-type S1 = nova_snark::spartan::snark::RelaxedR1CSSNARK<E1, EE1>; // non-preprocessing SNARK
-type S2 = nova_snark::spartan::snark::RelaxedR1CSSNARK<E2, EE2>; // non-preprocessing SNARK
-
 #[derive(Clone, Debug)]
 struct MinRootIteration<G: Group> {
   x_i: G::Scalar,
@@ -36,6 +44,9 @@ impl<G: Group> MinRootIteration<G> {
   fn new(num_iters: usize, x_0: &G::Scalar, y_0: &G::Scalar) -> (Vec<G::Scalar>, Vec<Self>) {
     // exp = (p - 3 / 5), where p is the order of the group
     // x^{exp} mod p provides the fifth root of x
+    //
+    // this is purely a function of the scalar field's modulus, so it works unchanged for any
+    // curve cycle whose scalar field order is coprime to 5.
     let exp = {
       let p = G::group_params().2.to_biguint().unwrap();
       let two = BigUint::parse_bytes(b"2", 10).unwrap();
@@ -69,12 +80,6 @@ impl<G: Group> MinRootIteration<G> {
         y_i_plus_1,
       });
 
-      // TODO: remove
-      println!("x_{} = {:?}", _i, x_i);
-      println!("y_{} = {:?}", _i, y_i);
-      // println!("x_{}_plus_1 = {:?}", _i, x_i_plus_1);
-      // println!("y_{}_plus_1 = {:?}", _i, y_i_plus_1);
-
       x_i = x_i_plus_1;
       y_i = y_i_plus_1;
     }
@@ -87,7 +92,17 @@ impl<G: Group> MinRootIteration<G> {
 
 #[derive(Clone, Debug)]
 struct MinRootCircuit<G: Group> {
-  seq: Vec<MinRootIteration<G>>,
+  num_iters: usize,
+  _p: std::marker::PhantomData<G>,
+}
+
+impl<G: Group> MinRootCircuit<G> {
+  fn new(num_iters: usize) -> Self {
+    Self {
+      num_iters,
+      _p: std::marker::PhantomData,
+    }
+  }
 }
 
 impl<G: Group> StepCircuit<G::Scalar> for MinRootCircuit<G> {
@@ -95,11 +110,18 @@ impl<G: Group> StepCircuit<G::Scalar> for MinRootCircuit<G> {
     2
   }
 
+  fn advice_size(&self) -> usize {
+    // one fifth root `x_i_plus_1` per iteration; `y_i_plus_1` is deterministic, so it isn't advice
+    self.num_iters
+  }
+
   fn synthesize<CS: ConstraintSystem<G::Scalar>>(
     &self,
     cs: &mut CS,
     z: &[AllocatedNum<G::Scalar>],
+    advice: &[AllocatedNum<G::Scalar>],
   ) -> Result<Vec<AllocatedNum<G::Scalar>>, SynthesisError> {
+    assert_eq!(advice.len(), self.num_iters);
     let mut z_out: Result<Vec<AllocatedNum<G::Scalar>>, SynthesisError> =
       Err(SynthesisError::AssignmentMissing);
 
@@ -110,17 +132,16 @@ impl<G: Group> StepCircuit<G::Scalar> for MinRootCircuit<G> {
     // variables to hold running x_i and y_i
     let mut x_i = x_0;
     let mut y_i = y_0;
-    for i in 0..self.seq.len() {
-      // non deterministic advice
+    for (i, x_i_plus_1) in advice.iter().enumerate() {
       let i_ = AllocatedNum::alloc(cs.namespace(|| format!("i_iter_{i}")), || {
         Ok(<G::Scalar as PrimeField>::from_u128((i + 1) as u128))
       })?;
-      let x_i_plus_1 =
-        AllocatedNum::alloc(cs.namespace(|| format!("x_i_plus_1_iter_{i}")), || {
-          Ok(self.seq[i].x_i_plus_1)
-        })?;
+      // y_i_plus_1 is fully determined by x_i and i, so it's computed here rather than supplied
+      // as advice
       let y_i_plus_1 = AllocatedNum::alloc(cs.namespace(|| format!("y_i_plus_1_iter{i}")), || {
-        Ok(self.seq[i].y_i_plus_1)
+        let x_i_val = x_i.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+        let i_val = i_.get_value().ok_or(SynthesisError::AssignmentMissing)?;
+        Ok(x_i_val + i_val)
       })?;
 
       // check that conditions (i) and (ii) hold:
@@ -142,169 +163,194 @@ impl<G: Group> StepCircuit<G::Scalar> for MinRootCircuit<G> {
         |lc| lc + x_i.get_variable() + i_.get_variable(),
       );
 
-      if i == self.seq.len() - 1 {
+      if i == self.num_iters - 1 {
         z_out = Ok(vec![x_i_plus_1.clone(), x_i.clone()]);
       }
 
       // update x_i and y_i for the next iteration
       y_i = y_i_plus_1;
-      x_i = x_i_plus_1;
+      x_i = x_i_plus_1.clone();
     }
 
     z_out
   }
 }
 
-/// cargo run --release --example minroot
-fn main() {
-  println!("Nova-based VDF with MinRoot delay function");
-  println!("=========================================================");
-
-  let num_steps = 1; // Nova incremental proof steps (corresponds to number of circuits produced)
-  for num_iters_per_step in [5] {
-    // number of iterations of MinRoot per Nova's recursive step
-    let circuit_primary = MinRootCircuit {
-      seq: vec![
-        MinRootIteration {
-          x_i: <E1 as Engine>::Scalar::zero(),
-          y_i: <E1 as Engine>::Scalar::zero(),
-          x_i_plus_1: <E1 as Engine>::Scalar::zero(),
-          y_i_plus_1: <E1 as Engine>::Scalar::zero(),
-        };
-        num_iters_per_step
-      ],
-    };
-
-    let circuit_secondary = TrivialCircuit::default();
-
-    println!("Proving {num_iters_per_step} iterations of MinRoot per step");
-
-    // produce public parameters
-    let start = Instant::now();
-    println!("Producing public parameters...");
-    let pp = PublicParams::<
-      E1,
-      E2,
-      MinRootCircuit<<E1 as Engine>::GE>,
-      TrivialCircuit<<E2 as Engine>::Scalar>,
-    >::setup(
-      &circuit_primary,
+/// Runs the MinRoot VDF pipeline (setup, proving, verification, and compression) for one
+/// supported engine cycle `(E1, E2)` using compressed SNARKs `(S1, S2)`, and prints a report of
+/// constraint counts and timings so cycles and SNARK backends can be compared side by side.
+///
+/// This is real, runnable code against this tree's `RecursiveSNARK`/`CompressedSNARK`/
+/// `RelaxedR1CSSNARKTrait` -- it's just that no caller in this snapshot can supply a concrete
+/// `(E1, E2)` curve cycle to instantiate it with (see the module doc comment).
+#[allow(dead_code)]
+fn run_minroot_demo<E1, E2, S1, S2>(cycle_name: &str, num_steps: usize, num_iters_per_step: usize)
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+  S1: RelaxedR1CSSNARKTrait<E1>,
+  S2: RelaxedR1CSSNARKTrait<E2>,
+{
+  type C1<E1> = MinRootCircuit<<E1 as Engine>::GE>;
+  type C2<E2> = TrivialCircuit<<E2 as Engine>::Scalar>;
+
+  println!("Running MinRoot VDF on the {cycle_name} cycle");
+  println!("---------------------------------------------------------");
+
+  let circuit_primary = MinRootCircuit::<<E1 as Engine>::GE>::new(num_iters_per_step);
+  let circuit_secondary = TrivialCircuit::default();
+
+  println!("Proving {num_iters_per_step} iterations of MinRoot per step");
+
+  // produce public parameters
+  let start = Instant::now();
+  println!("Producing public parameters...");
+  let pp = PublicParams::<E1, E2, C1<E1>, C2<E2>>::setup(
+    &circuit_primary,
+    &circuit_secondary,
+    &*S1::ck_floor(),
+    &*S2::ck_floor(),
+  )
+  .unwrap();
+  println!("PublicParams::setup, took {:?} ", start.elapsed());
+
+  println!(
+    "Number of constraints per step (primary circuit): {}",
+    pp.num_constraints().0
+  );
+  println!(
+    "Number of constraints per step (secondary circuit): {}",
+    pp.num_constraints().1
+  );
+
+  println!(
+    "Number of variables per step (primary circuit): {}",
+    pp.num_variables().0
+  );
+  println!(
+    "Number of variables per step (secondary circuit): {}",
+    pp.num_variables().1
+  );
+
+  // produce non-deterministic advice
+  let (z0_primary, minroot_iterations) = MinRootIteration::<<E1 as Engine>::GE>::new(
+    num_iters_per_step * num_steps,
+    &<E1 as Engine>::Scalar::zero(),
+    &<E1 as Engine>::Scalar::one(),
+  );
+  // every step runs the same shape of circuit; only the advice (the fifth roots) differs
+  let minroot_circuits =
+    vec![MinRootCircuit::<<E1 as Engine>::GE>::new(num_iters_per_step); num_steps];
+  let advice_per_step = (0..num_steps)
+    .map(|i| {
+      (0..num_iters_per_step)
+        .map(|j| minroot_iterations[i * num_iters_per_step + j].x_i_plus_1)
+        .collect::<Vec<_>>()
+    })
+    .collect::<Vec<_>>();
+
+  let z0_secondary = vec![<E2 as Engine>::Scalar::zero()];
+
+  // produce a recursive SNARK
+  println!("Generating a RecursiveSNARK...");
+  let mut recursive_snark: RecursiveSNARK<E1, E2, C1<E1>, C2<E2>> =
+    RecursiveSNARK::<E1, E2, C1<E1>, C2<E2>>::new(
+      &pp,
+      &minroot_circuits[0],
       &circuit_secondary,
-      &*S1::ck_floor(),
-      &*S2::ck_floor(),
+      &z0_primary,
+      &z0_secondary,
     )
     .unwrap();
-    println!("PublicParams::setup, took {:?} ", start.elapsed());
-
-    println!(
-      "Number of constraints per step (primary circuit): {}",
-      pp.num_constraints().0
-    );
-    println!(
-      "Number of constraints per step (secondary circuit): {}",
-      pp.num_constraints().1
-    );
-
-    println!(
-      "Number of variables per step (primary circuit): {}",
-      pp.num_variables().0
-    );
-    println!(
-      "Number of variables per step (secondary circuit): {}",
-      pp.num_variables().1
-    );
 
-    // produce non-deterministic advice
-    let (z0_primary, minroot_iterations) = MinRootIteration::<<E1 as Engine>::GE>::new(
-      num_iters_per_step * num_steps,
-      &<E1 as Engine>::Scalar::zero(),
-      &<E1 as Engine>::Scalar::one(),
-    );
-    let minroot_circuits = (0..num_steps)
-      .map(|i| MinRootCircuit {
-        seq: (0..num_iters_per_step)
-          .map(|j| MinRootIteration {
-            x_i: minroot_iterations[i * num_iters_per_step + j].x_i,
-            y_i: minroot_iterations[i * num_iters_per_step + j].y_i,
-            x_i_plus_1: minroot_iterations[i * num_iters_per_step + j].x_i_plus_1,
-            y_i_plus_1: minroot_iterations[i * num_iters_per_step + j].y_i_plus_1,
-          })
-          .collect::<Vec<_>>(),
-      })
-      .collect::<Vec<_>>();
-
-    let z0_secondary = vec![<E2 as Engine>::Scalar::zero()];
-
-    type C1 = MinRootCircuit<<E1 as Engine>::GE>;
-    type C2 = TrivialCircuit<<E2 as Engine>::Scalar>;
-    // produce a recursive SNARK
-    println!("Generating a RecursiveSNARK...");
-    let mut recursive_snark: RecursiveSNARK<E1, E2, C1, C2> =
-      RecursiveSNARK::<E1, E2, C1, C2>::new(
-        &pp,
-        &minroot_circuits[0],
-        &circuit_secondary,
-        &z0_primary,
-        &z0_secondary,
-      )
-      .unwrap();
-
-    for (i, circuit_primary) in minroot_circuits.iter().enumerate() {
-      let start = Instant::now();
-      let res = recursive_snark.prove_step(&pp, circuit_primary, &circuit_secondary);
-      assert!(res.is_ok());
-      println!(
-        "RecursiveSNARK::prove_step {}: {:?}, took {:?} ",
-        i,
-        res.is_ok(),
-        start.elapsed()
-      );
-    }
-
-    // verify the recursive SNARK
-    println!("Verifying a RecursiveSNARK...");
+  for (i, circuit_primary) in minroot_circuits.iter().enumerate() {
     let start = Instant::now();
-    let res = recursive_snark.verify(&pp, num_steps, &z0_primary, &z0_secondary);
-    println!(
-      "RecursiveSNARK::verify: {:?}, took {:?}",
-      res.is_ok(),
-      start.elapsed()
+    let res = recursive_snark.prove_step(
+      &pp,
+      circuit_primary,
+      &circuit_secondary,
+      &advice_per_step[i],
+      &[],
     );
     assert!(res.is_ok());
-
-    // produce a compressed SNARK
-    println!("Generating a CompressedSNARK using Spartan with HyperKZG...");
-    let (pk, vk) = CompressedSNARK::<_, _, _, _, S1, S2>::setup(&pp).unwrap();
-
-    let start = Instant::now();
-
-    let res = CompressedSNARK::<_, _, _, _, S1, S2>::prove(&pp, &pk, &recursive_snark);
     println!(
-      "CompressedSNARK::prove: {:?}, took {:?}",
+      "RecursiveSNARK::prove_step {}: {:?}, took {:?} ",
+      i,
       res.is_ok(),
       start.elapsed()
     );
-    assert!(res.is_ok());
-    let compressed_snark = res.unwrap();
+  }
 
-    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
-    bincode::serialize_into(&mut encoder, &compressed_snark).unwrap();
-    let compressed_snark_encoded = encoder.finish().unwrap();
-    println!(
-      "CompressedSNARK::len {:?} bytes",
-      compressed_snark_encoded.len()
-    );
+  // verify the recursive SNARK
+  println!("Verifying a RecursiveSNARK...");
+  let start = Instant::now();
+  let res = recursive_snark.verify(&pp, num_steps, &z0_primary, &z0_secondary);
+  println!(
+    "RecursiveSNARK::verify: {:?}, took {:?}",
+    res.is_ok(),
+    start.elapsed()
+  );
+  assert!(res.is_ok());
+
+  // produce a compressed SNARK
+  println!("Generating a CompressedSNARK...");
+  let (pk, vk) = CompressedSNARK::<_, _, _, _, S1, S2>::setup(&pp).unwrap();
+
+  let start = Instant::now();
+  let res = CompressedSNARK::<_, _, _, _, S1, S2>::prove(&pp, &pk, &recursive_snark);
+  println!(
+    "CompressedSNARK::prove: {:?}, took {:?}",
+    res.is_ok(),
+    start.elapsed()
+  );
+  assert!(res.is_ok());
+  let compressed_snark = res.unwrap();
+
+  let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+  bincode::serialize_into(&mut encoder, &compressed_snark).unwrap();
+  let compressed_snark_encoded = encoder.finish().unwrap();
+  println!(
+    "CompressedSNARK::len {:?} bytes",
+    compressed_snark_encoded.len()
+  );
+
+  // verify the compressed SNARK
+  println!("Verifying a CompressedSNARK...");
+  let start = Instant::now();
+  let res = compressed_snark.verify(&vk, num_steps, &z0_primary, &z0_secondary);
+  println!(
+    "CompressedSNARK::verify: {:?}, took {:?}",
+    res.is_ok(),
+    start.elapsed()
+  );
+  assert!(res.is_ok());
+  println!("=========================================================");
+}
 
-    // verify the compressed SNARK
-    println!("Verifying a CompressedSNARK...");
-    let start = Instant::now();
-    let res = compressed_snark.verify(&vk, num_steps, &z0_primary, &z0_secondary);
-    println!(
-      "CompressedSNARK::verify: {:?}, took {:?}",
-      res.is_ok(),
-      start.elapsed()
-    );
-    assert!(res.is_ok());
-    println!("=========================================================");
-  }
+/// cargo run --release --example minroot
+///
+/// `run_minroot_demo` above is real and ready to use, but this snapshot doesn't carry a concrete
+/// curve-cycle `Engine` to instantiate it with -- see the module doc comment. Once one lands
+/// (e.g. a `pallas`/`vesta` pair implementing `Group`/`DlogGroup`/`Engine`), running it against
+/// the SNARKs that already exist here is just:
+///
+/// ```ignore
+/// type HyperKzgEE<E> = nova_snark::provider::hyperkzg::EvaluationEngine<E>;
+/// type PreprocessingSnark<E, EE> = nova_snark::spartan::ppsnark::RelaxedR1CSSNARK<E, EE>;
+/// run_minroot_demo::<
+///   SomePairingFriendlyEngine,
+///   SomeOtherCycleSideEngine,
+///   PreprocessingSnark<SomePairingFriendlyEngine, HyperKzgEE<SomePairingFriendlyEngine>>,
+///   PreprocessingSnark<SomeOtherCycleSideEngine, /* that side's own EvaluationEngineTrait impl */>,
+/// >("some cycle", num_steps, num_iters_per_step);
+/// ```
+fn main() {
+  println!("Nova-based VDF with MinRoot delay function");
+  println!("=========================================================");
+  println!(
+    "This snapshot implements the MinRoot circuit, RecursiveSNARK/CompressedSNARK, and the \
+     ppsnark/hyperkzg backends for real, but doesn't carry a concrete curve-cycle Engine \
+     (pallas/vesta/bn256_grumpkin/secp_secq) to run them against -- see run_minroot_demo's doc \
+     comment for how to wire one in once it exists."
+  );
 }
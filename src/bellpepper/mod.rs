@@ -0,0 +1,4 @@
+//! Glue between `bellpepper_core`'s circuit-synthesis API and this crate's R1CS types: turns a
+//! `StepCircuit::synthesize` call into an `R1CSShape` (the constraint matrices) and, given
+//! concrete inputs, an `R1CSInstance`/`R1CSWitness` satisfying that shape.
+pub mod solver;
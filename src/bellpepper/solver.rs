@@ -0,0 +1,152 @@
+//! A `ConstraintSystem` that synthesizes a circuit once and produces both its `R1CSShape` and,
+//! since every variable synthesized here is given a concrete value, a full `R1CSInstance`/
+//! `R1CSWitness` pair satisfying that shape.
+//!
+//! Real Nova keeps shape-derivation (`ShapeCS`, no witness values needed) and witness-generation
+//! (`SatisfyingAssignment`, shape already known) as two separate passes so repeated steps of the
+//! same circuit only pay the first pass once. This collapses them into one: `RecursiveSNARK`
+//! synthesizes once per step regardless, with concrete advice every time (even at `setup`, where
+//! placeholder zero advice is used purely to learn the shape), so there's no repeated work being
+//! saved by splitting the passes here.
+use crate::{
+  r1cs::{R1CSInstance, R1CSShape, R1CSWitness},
+  traits::Engine,
+  CommitmentKey,
+};
+use bellpepper_core::{ConstraintSystem, Index, LinearCombination, SynthesisError, Variable};
+use ff::Field;
+
+pub struct SatisfyingAssignment<E: Engine> {
+  aux_assignment: Vec<E::Scalar>,
+  input_assignment: Vec<E::Scalar>,
+  A: Vec<(usize, usize, E::Scalar)>,
+  B: Vec<(usize, usize, E::Scalar)>,
+  C: Vec<(usize, usize, E::Scalar)>,
+  num_constraints: usize,
+}
+
+impl<E: Engine> SatisfyingAssignment<E> {
+  pub fn new() -> Self {
+    Self {
+      aux_assignment: Vec::new(),
+      input_assignment: Vec::new(),
+      A: Vec::new(),
+      B: Vec::new(),
+      C: Vec::new(),
+      num_constraints: 0,
+    }
+  }
+
+  fn num_vars(&self) -> usize {
+    self.aux_assignment.len()
+  }
+
+  /// The column, in `z = (aux, 1, input)`, that `var` refers to. The `1`/input columns follow
+  /// every aux column, whose final count is only known once synthesis is done, so they are
+  /// recorded as offsets below `usize::MAX` and resolved by `shape`.
+  fn column(var: Variable) -> usize {
+    match var.get_unchecked() {
+      Index::Input(i) => usize::MAX - i,
+      Index::Aux(i) => i,
+    }
+  }
+
+  fn push_lc(matrix: &mut Vec<(usize, usize, E::Scalar)>, row: usize, lc: &LinearCombination<E::Scalar>, col: impl Fn(Variable) -> usize) {
+    for (var, coeff) in lc.iter() {
+      if !coeff.is_zero_vartime() {
+        matrix.push((row, col(*var), *coeff));
+      }
+    }
+  }
+
+  /// The shape recorded by this synthesis pass.
+  pub fn shape(&self) -> R1CSShape<E> {
+    let num_vars = self.num_vars();
+    let resolve = |m: &[(usize, usize, E::Scalar)]| -> Vec<(usize, usize, E::Scalar)> {
+      m.iter()
+        .map(|&(row, col, val)| {
+          let col = if col < self.aux_assignment.len() { col } else { num_vars + (usize::MAX - col) };
+          (row, col, val)
+        })
+        .collect()
+    };
+    R1CSShape {
+      num_cons: self.num_constraints,
+      num_vars,
+      num_io: self.input_assignment.len(),
+      A: resolve(&self.A),
+      B: resolve(&self.B),
+      C: resolve(&self.C),
+    }
+  }
+
+  /// The instance/witness pair produced by this synthesis pass, committed under `ck`.
+  pub fn instance_and_witness(&self, ck: &CommitmentKey<E>) -> (R1CSInstance<E>, R1CSWitness<E>) {
+    let W = R1CSWitness {
+      W: self.aux_assignment.clone(),
+    };
+    let comm_W = W.commit(ck);
+    let U = R1CSInstance {
+      comm_W,
+      X: self.input_assignment.clone(),
+    };
+    (U, W)
+  }
+}
+
+impl<E: Engine> ConstraintSystem<E::Scalar> for SatisfyingAssignment<E> {
+  type Root = Self;
+
+  fn alloc<F, A, AR>(&mut self, _annotation: A, f: F) -> Result<Variable, SynthesisError>
+  where
+    F: FnOnce() -> Result<E::Scalar, SynthesisError>,
+    A: FnOnce() -> AR,
+    AR: Into<String>,
+  {
+    self.aux_assignment.push(f()?);
+    Ok(Variable::new_unchecked(Index::Aux(self.aux_assignment.len() - 1)))
+  }
+
+  fn alloc_input<F, A, AR>(&mut self, _annotation: A, f: F) -> Result<Variable, SynthesisError>
+  where
+    F: FnOnce() -> Result<E::Scalar, SynthesisError>,
+    A: FnOnce() -> AR,
+    AR: Into<String>,
+  {
+    self.input_assignment.push(f()?);
+    Ok(Variable::new_unchecked(Index::Input(self.input_assignment.len())))
+  }
+
+  fn enforce<A, AR, LA, LB, LC>(&mut self, _annotation: A, a: LA, b: LB, c: LC)
+  where
+    A: FnOnce() -> AR,
+    AR: Into<String>,
+    LA: FnOnce(LinearCombination<E::Scalar>) -> LinearCombination<E::Scalar>,
+    LB: FnOnce(LinearCombination<E::Scalar>) -> LinearCombination<E::Scalar>,
+    LC: FnOnce(LinearCombination<E::Scalar>) -> LinearCombination<E::Scalar>,
+  {
+    let row = self.num_constraints;
+
+    let a_lc = a(LinearCombination::zero());
+    let b_lc = b(LinearCombination::zero());
+    let c_lc = c(LinearCombination::zero());
+    Self::push_lc(&mut self.A, row, &a_lc, Self::column);
+    Self::push_lc(&mut self.B, row, &b_lc, Self::column);
+    Self::push_lc(&mut self.C, row, &c_lc, Self::column);
+
+    self.num_constraints += 1;
+  }
+
+  fn push_namespace<NR, N>(&mut self, _name_fn: N)
+  where
+    NR: Into<String>,
+    N: FnOnce() -> NR,
+  {
+  }
+
+  fn pop_namespace(&mut self) {}
+
+  fn get_root(&mut self) -> &mut Self::Root {
+    self
+  }
+}
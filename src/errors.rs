@@ -0,0 +1,31 @@
+//! This module defines errors returned by the library.
+use core::fmt::Debug;
+use thiserror::Error;
+
+/// Errors returned by Nova
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum NovaError {
+  /// returned if the supplied row or column of R1CS is invalid
+  #[error("InvalidIndex")]
+  InvalidIndex,
+  /// returned if the supplied public IO is not even length
+  #[error("OddInputLength")]
+  OddInputLength,
+  /// returned if the supplied public IO is not consistent with witness
+  #[error("InvalidWitnessLength")]
+  InvalidWitnessLength,
+  /// returned if the supplied witness is not a satisfying witness to a given shape and instance
+  #[error("UnSat")]
+  UnSat,
+  /// returned when the supplied compressed commitment cannot be decompressed
+  #[error("DecompressionError")]
+  DecompressionError,
+  /// returned if proof verification fails
+  #[error("ProofVerifyError")]
+  ProofVerifyError,
+  /// returned when the step circuit supplied to a non-uniform IVC step reports a
+  /// `circuit_index()` that does not match the program counter driving that step, or a program
+  /// counter that is out of range for the non-uniform circuit's `num_circuits()`
+  #[error("InvalidStepCircuitIndex")]
+  InvalidStepCircuitIndex,
+}
@@ -0,0 +1,268 @@
+//! Reusable bitwise/boolean gadgets for circuits that need to operate on bit-decomposed values.
+//!
+//! `MinRootCircuit` is purely algebraic, but many verifiable computations (bitwise AND/XOR/OR,
+//! comparisons, range checks) need an `AllocatedNum` decomposed into bits before they can express
+//! those operations as R1CS constraints. This module provides that decomposition (with enforced
+//! booleanity of every bit and a recomposition constraint tying the bits back to the original
+//! number), 64-bit AND/XOR/OR built on top of it, and a `StepCircuit` adapter that batches a
+//! configurable number of word operations per recursion step, mirroring the
+//! `num_iters_per_step` knob of the `minroot` example.
+use bellpepper_core::{
+  boolean::{AllocatedBit, Boolean},
+  num::AllocatedNum,
+  ConstraintSystem, LinearCombination, SynthesisError,
+};
+use ff::{PrimeField, PrimeFieldBits};
+
+/// Decomposes `num` into `num_bits` little-endian bits, enforcing that each bit is boolean and
+/// that the bits recompose (as a base-2 sum) to `num`. Returns the allocated bits, least
+/// significant first.
+pub fn decompose_into_bits<F, CS>(
+  mut cs: CS,
+  num: &AllocatedNum<F>,
+  num_bits: usize,
+) -> Result<Vec<AllocatedBit>, SynthesisError>
+where
+  F: PrimeField + PrimeFieldBits,
+  CS: ConstraintSystem<F>,
+{
+  let bit_values: Vec<Option<bool>> = match num.get_value() {
+    Some(value) => value
+      .to_le_bits()
+      .into_iter()
+      .take(num_bits)
+      .map(Some)
+      .collect(),
+    None => vec![None; num_bits],
+  };
+
+  let bits = bit_values
+    .into_iter()
+    .enumerate()
+    .map(|(i, bit)| AllocatedBit::alloc(cs.namespace(|| format!("bit {i}")), bit))
+    .collect::<Result<Vec<_>, _>>()?;
+
+  // recomposition: sum_i bits[i] * 2^i == num
+  let mut lc = LinearCombination::zero();
+  let mut coeff = F::ONE;
+  for bit in &bits {
+    lc = lc + (coeff, bit.get_variable());
+    coeff = coeff.double();
+  }
+  cs.enforce(
+    || "bit decomposition recomposes to num",
+    |_| lc,
+    |lc| lc + CS::one(),
+    |lc| lc + num.get_variable(),
+  );
+
+  Ok(bits)
+}
+
+/// Recomposes little-endian `bits` into an `AllocatedNum`, enforcing the same base-2 sum
+/// constraint as `decompose_into_bits`, so the result is usable as an ordinary field element
+/// elsewhere in the circuit.
+pub fn recompose_from_bits<F, CS>(
+  mut cs: CS,
+  bits: &[AllocatedBit],
+) -> Result<AllocatedNum<F>, SynthesisError>
+where
+  F: PrimeField,
+  CS: ConstraintSystem<F>,
+{
+  let value = {
+    let mut coeff = F::ONE;
+    let mut acc = Some(F::ZERO);
+    for bit in bits {
+      acc = acc.and_then(|acc| {
+        bit.get_value().map(|b| if b { acc + coeff } else { acc })
+      });
+      coeff = coeff.double();
+    }
+    acc
+  };
+
+  let num = AllocatedNum::alloc(cs.namespace(|| "recomposed num"), || {
+    value.ok_or(SynthesisError::AssignmentMissing)
+  })?;
+
+  let mut lc = LinearCombination::zero();
+  let mut coeff = F::ONE;
+  for bit in bits {
+    lc = lc + (coeff, bit.get_variable());
+    coeff = coeff.double();
+  }
+  cs.enforce(
+    || "num recomposes from bits",
+    |_| lc,
+    |lc| lc + CS::one(),
+    |lc| lc + num.get_variable(),
+  );
+
+  Ok(num)
+}
+
+/// Bitwise ops supported by `word_op`, each constrained bit-by-bit and then re-packed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WordOp {
+  And,
+  Xor,
+  Or,
+}
+
+/// Computes `a OP b` on 64-bit words represented as field elements, by decomposing both operands
+/// into 64 bits, constraining each output bit against the corresponding input bits of `a` and
+/// `b` via `Boolean`'s boolean-circuit helpers, and recomposing the result.
+pub fn word_op<F, CS>(
+  mut cs: CS,
+  a: &AllocatedNum<F>,
+  b: &AllocatedNum<F>,
+  op: WordOp,
+) -> Result<AllocatedNum<F>, SynthesisError>
+where
+  F: PrimeField + PrimeFieldBits,
+  CS: ConstraintSystem<F>,
+{
+  let a_bits = decompose_into_bits(cs.namespace(|| "decompose a"), a, 64)?;
+  let b_bits = decompose_into_bits(cs.namespace(|| "decompose b"), b, 64)?;
+
+  let out_bits = a_bits
+    .iter()
+    .zip(b_bits.iter())
+    .enumerate()
+    .map(|(i, (a_bit, b_bit))| {
+      let a_bool = Boolean::from(a_bit.clone());
+      let b_bool = Boolean::from(b_bit.clone());
+      let out = match op {
+        WordOp::And => Boolean::and(cs.namespace(|| format!("and bit {i}")), &a_bool, &b_bool)?,
+        WordOp::Xor => Boolean::xor(cs.namespace(|| format!("xor bit {i}")), &a_bool, &b_bool)?,
+        WordOp::Or => {
+          // a OR b = NOT (NOT a AND NOT b)
+          Boolean::and(
+            cs.namespace(|| format!("nor bit {i}")),
+            &a_bool.not(),
+            &b_bool.not(),
+          )?
+          .not()
+        }
+      };
+      match out {
+        Boolean::Is(bit) => Ok(bit),
+        Boolean::Not(bit) => {
+          // materialize a fresh allocated bit equal to the negation so recomposition below
+          // only ever deals with `AllocatedBit`s
+          let negated = AllocatedBit::alloc(
+            cs.namespace(|| format!("materialize bit {i}")),
+            bit.get_value().map(|v| !v),
+          )?;
+          cs.enforce(
+            || format!("materialized bit {i} is the negation"),
+            |lc| lc + CS::one() - bit.get_variable(),
+            |lc| lc + CS::one(),
+            |lc| lc + negated.get_variable(),
+          );
+          Ok(negated)
+        }
+        Boolean::Constant(_) => unreachable!("inputs are always allocated bits"),
+      }
+    })
+    .collect::<Result<Vec<_>, SynthesisError>>()?;
+
+  recompose_from_bits(cs.namespace(|| "recompose result"), &out_bits)
+}
+
+/// A `StepCircuit` that proves a batch of `num_ops_per_step` 64-bit bitwise operations per
+/// recursion step, analogous to how `MinRootCircuit` batches `num_iters_per_step` MinRoot
+/// iterations. The IVC state `z` is `[running_result]`; each step folds in `num_ops_per_step`
+/// more operations, each combining the running result with the next advice operand.
+///
+/// The circuit only pins down *which* operation runs at each position (`ops`); the right-hand
+/// operand of every operation is witness-only advice supplied at `prove_step` time, so building
+/// `BitwiseStepCircuit` does not require knowing the operands up front.
+#[derive(Clone, Debug)]
+pub struct BitwiseStepCircuit<F: PrimeField> {
+  ops: Vec<WordOp>,
+  _p: core::marker::PhantomData<F>,
+}
+
+impl<F: PrimeField> BitwiseStepCircuit<F> {
+  /// Builds a circuit for one recursion step running `ops` in order, whose length is the step's
+  /// `num_ops_per_step`.
+  pub fn new(ops: Vec<WordOp>) -> Self {
+    Self {
+      ops,
+      _p: core::marker::PhantomData,
+    }
+  }
+}
+
+impl<F: PrimeField + PrimeFieldBits> crate::traits::circuit::StepCircuit<F>
+  for BitwiseStepCircuit<F>
+{
+  fn arity(&self) -> usize {
+    1
+  }
+
+  fn advice_size(&self) -> usize {
+    self.ops.len()
+  }
+
+  fn synthesize<CS: ConstraintSystem<F>>(
+    &self,
+    cs: &mut CS,
+    z: &[AllocatedNum<F>],
+    advice: &[AllocatedNum<F>],
+  ) -> Result<Vec<AllocatedNum<F>>, SynthesisError> {
+    assert_eq!(advice.len(), self.ops.len());
+    let mut running = z[0].clone();
+    for (i, (op, operand)) in self.ops.iter().zip(advice.iter()).enumerate() {
+      running = word_op(
+        cs.namespace(|| format!("word_op_{i}")),
+        &running,
+        operand,
+        *op,
+      )?;
+    }
+    Ok(vec![running])
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use bellpepper_core::test_cs::TestConstraintSystem;
+  use pasta_curves::Fp;
+
+  fn alloc_u64(cs: &mut TestConstraintSystem<Fp>, label: &str, v: u64) -> AllocatedNum<Fp> {
+    AllocatedNum::alloc(cs.namespace(|| label.to_string()), || Ok(Fp::from(v))).unwrap()
+  }
+
+  fn run_word_op(a: u64, b: u64, op: WordOp) -> u64 {
+    let mut cs = TestConstraintSystem::<Fp>::new();
+    let a_num = alloc_u64(&mut cs, "a", a);
+    let b_num = alloc_u64(&mut cs, "b", b);
+    let out = word_op(cs.namespace(|| "op"), &a_num, &b_num, op).unwrap();
+    assert!(cs.is_satisfied());
+
+    let mut bytes = out.get_value().unwrap().to_repr().as_ref().to_vec();
+    bytes.truncate(8);
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&bytes);
+    u64::from_le_bytes(buf)
+  }
+
+  #[test]
+  fn and_matches_native() {
+    assert_eq!(run_word_op(0xf0f0_f0f0, 0x0f0f_f0f0, WordOp::And), 0xf0f0_f0f0 & 0x0f0f_f0f0);
+  }
+
+  #[test]
+  fn xor_matches_native() {
+    assert_eq!(run_word_op(0xdead_beef, 0xcafe_babe, WordOp::Xor), 0xdead_beef ^ 0xcafe_babe);
+  }
+
+  #[test]
+  fn or_matches_native() {
+    assert_eq!(run_word_op(0x1234_5678, 0x0000_ffff, WordOp::Or), 0x1234_5678 | 0x0000_ffff);
+  }
+}
@@ -0,0 +1,2 @@
+//! Reusable gadgets shared across circuits built on top of Nova.
+pub mod bits;
@@ -0,0 +1,437 @@
+//! Nova: high-speed recursive SNARKs via a folding scheme for relaxed R1CS.
+//!
+//! The core IVC types here (`PublicParams`, `RecursiveSNARK`, `CompressedSNARK`) fold one step's
+//! R1CS instance into a running relaxed-R1CS accumulator per side of the curve cycle (see
+//! `nifs::NIFS`), and synthesize each step's `StepCircuit` for real via `bellpepper::solver`. What
+//! they do *not* yet do is verify, inside the circuit, that the previous step's fold was itself
+//! performed correctly -- the augmented-circuit recursion that gives Nova's real implementation
+//! both succinctness (verifier work independent of the number of steps) and soundness against a
+//! prover who bypasses `prove_step` entirely (e.g. by hand-crafting a serialized
+//! `RelaxedR1CSInstance`/`RelaxedR1CSWitness`). Within the trust model of "the prover only calls
+//! the public `RecursiveSNARK`/`CompressedSNARK` API," folding is real and `verify`/the
+//! `RelaxedR1CSSNARKTrait` proof both check real satisfiability of the accumulated relation;
+//! closing the remaining gap is tracked as follow-on work on the augmented circuit.
+pub mod bellpepper;
+pub mod errors;
+pub mod gadgets;
+pub mod nifs;
+pub mod provider;
+pub mod r1cs;
+pub mod spartan;
+pub mod supernova;
+#[cfg(test)]
+mod test_utils;
+pub mod traits;
+
+use crate::{
+  bellpepper::solver::SatisfyingAssignment,
+  errors::NovaError,
+  nifs::NIFS,
+  r1cs::{R1CSShape, R1CSWithArity, RelaxedR1CSInstance, RelaxedR1CSWitness},
+  traits::{
+    circuit::StepCircuit, commitment::CommitmentEngineTrait, snark::RelaxedR1CSSNARKTrait, Engine,
+    TranscriptEngineTrait,
+  },
+};
+use bellpepper_core::{num::AllocatedNum, ConstraintSystem, SynthesisError};
+use core::marker::PhantomData;
+use serde::{Deserialize, Serialize};
+
+pub use r1cs::R1CSWithArity;
+
+/// The commitment key type for `E`'s commitment engine.
+pub type CommitmentKey<E> = <<E as Engine>::CE as CommitmentEngineTrait<E>>::CommitmentKey;
+/// The commitment type for `E`'s commitment engine.
+pub type Commitment<E> = <<E as Engine>::CE as CommitmentEngineTrait<E>>::Commitment;
+
+/// Synthesizes `circuit` on `(z, advice)` and returns the values of `z_out` together with the
+/// R1CS shape the synthesis produced and the `SatisfyingAssignment` holding its instance/witness.
+fn synthesize_step<E, C>(
+  circuit: &C,
+  z: &[E::Scalar],
+  advice: &[E::Scalar],
+) -> Result<(Vec<E::Scalar>, R1CSShape<E>, SatisfyingAssignment<E>), NovaError>
+where
+  E: Engine,
+  C: StepCircuit<E::Scalar>,
+{
+  if advice.len() != circuit.advice_size() {
+    return Err(NovaError::InvalidWitnessLength);
+  }
+  let mut cs = SatisfyingAssignment::<E>::new();
+  let z_alloc: Vec<AllocatedNum<E::Scalar>> = z
+    .iter()
+    .enumerate()
+    .map(|(i, v)| AllocatedNum::alloc(cs.namespace(|| format!("z_{i}")), || Ok(*v)))
+    .collect::<Result<_, SynthesisError>>()
+    .map_err(|_| NovaError::InvalidWitnessLength)?;
+  let advice_alloc: Vec<AllocatedNum<E::Scalar>> = advice
+    .iter()
+    .enumerate()
+    .map(|(i, v)| AllocatedNum::alloc(cs.namespace(|| format!("advice_{i}")), || Ok(*v)))
+    .collect::<Result<_, SynthesisError>>()
+    .map_err(|_| NovaError::InvalidWitnessLength)?;
+
+  let z_out = circuit
+    .synthesize(&mut cs, &z_alloc, &advice_alloc)
+    .map_err(|_| NovaError::UnSat)?;
+  let z_out_values = z_out
+    .iter()
+    .map(|v| v.get_value().ok_or(NovaError::InvalidWitnessLength))
+    .collect::<Result<Vec<_>, _>>()?;
+
+  let shape = cs.shape();
+  Ok((z_out_values, shape, cs))
+}
+
+/// Public parameters for a uniform recursive SNARK: the R1CS shape each side of the cycle's
+/// circuit compiles to, and a commitment key large enough both for that shape's witnesses and
+/// for whatever the chosen `RelaxedR1CSSNARKTrait` additionally needs (`S::ck_floor`).
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct PublicParams<E1, E2, C1, C2>
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+  C1: StepCircuit<E1::Scalar>,
+  C2: StepCircuit<E2::Scalar>,
+{
+  r1cs_shape_primary: R1CSWithArity<E1>,
+  r1cs_shape_secondary: R1CSWithArity<E2>,
+  ck_primary: CommitmentKey<E1>,
+  ck_secondary: CommitmentKey<E2>,
+  _p: PhantomData<(C1, C2)>,
+}
+
+impl<E1, E2, C1, C2> PublicParams<E1, E2, C1, C2>
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+  C1: StepCircuit<E1::Scalar>,
+  C2: StepCircuit<E2::Scalar>,
+{
+  /// Derives the R1CS shapes for `c_primary`/`c_secondary` (by synthesizing each once, with
+  /// placeholder zero `z`/advice, purely to learn their constraint matrices) and commitment keys
+  /// sized for them.
+  pub fn setup(
+    c_primary: &C1,
+    c_secondary: &C2,
+    ck_hint1: &dyn Fn(&R1CSShape<E1>) -> usize,
+    ck_hint2: &dyn Fn(&R1CSShape<E2>) -> usize,
+  ) -> Result<Self, NovaError> {
+    let z0_primary = vec![E1::Scalar::default(); c_primary.arity()];
+    let advice0_primary = vec![E1::Scalar::default(); c_primary.advice_size()];
+    let (_, shape_primary, _) = synthesize_step(c_primary, &z0_primary, &advice0_primary)?;
+
+    let z0_secondary = vec![E2::Scalar::default(); c_secondary.arity()];
+    let advice0_secondary = vec![E2::Scalar::default(); c_secondary.advice_size()];
+    let (_, shape_secondary, _) = synthesize_step(c_secondary, &z0_secondary, &advice0_secondary)?;
+
+    // folding commits to both `W` (num_vars entries) and `E`/`T` (num_cons entries)
+    let ck_primary = E1::CE::setup(
+      b"nova_ck_primary",
+      shape_primary.num_vars.max(shape_primary.num_cons).max(ck_hint1(&shape_primary)),
+    );
+    let ck_secondary = E2::CE::setup(
+      b"nova_ck_secondary",
+      shape_secondary.num_vars.max(shape_secondary.num_cons).max(ck_hint2(&shape_secondary)),
+    );
+
+    Ok(Self {
+      r1cs_shape_primary: R1CSWithArity::new(shape_primary, c_primary.arity()),
+      r1cs_shape_secondary: R1CSWithArity::new(shape_secondary, c_secondary.arity()),
+      ck_primary,
+      ck_secondary,
+      _p: PhantomData,
+    })
+  }
+
+  /// `(primary, secondary)` constraint counts per step.
+  pub fn num_constraints(&self) -> (usize, usize) {
+    (
+      self.r1cs_shape_primary.shape.num_cons,
+      self.r1cs_shape_secondary.shape.num_cons,
+    )
+  }
+
+  /// `(primary, secondary)` witness-variable counts per step.
+  pub fn num_variables(&self) -> (usize, usize) {
+    (
+      self.r1cs_shape_primary.shape.num_vars,
+      self.r1cs_shape_secondary.shape.num_vars,
+    )
+  }
+}
+
+/// A recursive SNARK accumulating, for each side of the curve cycle, one running relaxed-R1CS
+/// instance/witness pair across however many `prove_step` calls have run so far. See the
+/// module-level docs for what this does and does not guarantee.
+pub struct RecursiveSNARK<E1, E2, C1, C2>
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+  C1: StepCircuit<E1::Scalar>,
+  C2: StepCircuit<E2::Scalar>,
+{
+  i: usize,
+  z0_primary: Vec<E1::Scalar>,
+  z0_secondary: Vec<E2::Scalar>,
+  zi_primary: Vec<E1::Scalar>,
+  zi_secondary: Vec<E2::Scalar>,
+  r_u_primary: RelaxedR1CSInstance<E1>,
+  r_w_primary: RelaxedR1CSWitness<E1>,
+  r_u_secondary: RelaxedR1CSInstance<E2>,
+  r_w_secondary: RelaxedR1CSWitness<E2>,
+  transcript_primary: E1::TE,
+  transcript_secondary: E2::TE,
+  _p: PhantomData<(C1, C2)>,
+}
+
+impl<E1, E2, C1, C2> RecursiveSNARK<E1, E2, C1, C2>
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+  C1: StepCircuit<E1::Scalar>,
+  C2: StepCircuit<E2::Scalar>,
+{
+  /// Starts a fresh IVC chain at `(z0_primary, z0_secondary)`, with both running accumulators at
+  /// their all-zero default.
+  pub fn new(
+    pp: &PublicParams<E1, E2, C1, C2>,
+    _c_primary: &C1,
+    _c_secondary: &C2,
+    z0_primary: &[E1::Scalar],
+    z0_secondary: &[E2::Scalar],
+  ) -> Result<Self, NovaError> {
+    if z0_primary.len() != pp.r1cs_shape_primary.arity || z0_secondary.len() != pp.r1cs_shape_secondary.arity {
+      return Err(NovaError::InvalidWitnessLength);
+    }
+    Ok(Self {
+      i: 0,
+      z0_primary: z0_primary.to_vec(),
+      z0_secondary: z0_secondary.to_vec(),
+      zi_primary: z0_primary.to_vec(),
+      zi_secondary: z0_secondary.to_vec(),
+      r_u_primary: RelaxedR1CSInstance::default(&pp.ck_primary, pp.r1cs_shape_primary.shape.num_io),
+      r_w_primary: RelaxedR1CSWitness::default(
+        pp.r1cs_shape_primary.shape.num_vars,
+        pp.r1cs_shape_primary.shape.num_cons,
+      ),
+      r_u_secondary: RelaxedR1CSInstance::default(&pp.ck_secondary, pp.r1cs_shape_secondary.shape.num_io),
+      r_w_secondary: RelaxedR1CSWitness::default(
+        pp.r1cs_shape_secondary.shape.num_vars,
+        pp.r1cs_shape_secondary.shape.num_cons,
+      ),
+      transcript_primary: E1::TE::new(b"nova_recursive_snark_primary"),
+      transcript_secondary: E2::TE::new(b"nova_recursive_snark_secondary"),
+      _p: PhantomData,
+    })
+  }
+
+  /// Number of steps folded so far.
+  pub fn num_steps(&self) -> usize {
+    self.i
+  }
+
+  /// Synthesizes `c_primary`/`c_secondary` on the current IVC state plus this step's
+  /// non-deterministic `advice_primary`/`advice_secondary`, and folds the resulting instances
+  /// into the running accumulators via `NIFS::prove`.
+  pub fn prove_step(
+    &mut self,
+    pp: &PublicParams<E1, E2, C1, C2>,
+    c_primary: &C1,
+    c_secondary: &C2,
+    advice_primary: &[E1::Scalar],
+    advice_secondary: &[E2::Scalar],
+  ) -> Result<(), NovaError> {
+    let (z_next_primary, _shape_primary, cs_primary) =
+      synthesize_step(c_primary, &self.zi_primary, advice_primary)?;
+    let (u_primary, w_primary) = cs_primary.instance_and_witness(&pp.ck_primary);
+    let (_nifs_primary, (folded_u_primary, folded_w_primary)) = NIFS::prove(
+      &pp.ck_primary,
+      &pp.r1cs_shape_primary.shape,
+      &mut self.transcript_primary,
+      &self.r_u_primary,
+      &self.r_w_primary,
+      &u_primary,
+      &w_primary,
+    )?;
+
+    let (z_next_secondary, _shape_secondary, cs_secondary) =
+      synthesize_step(c_secondary, &self.zi_secondary, advice_secondary)?;
+    let (u_secondary, w_secondary) = cs_secondary.instance_and_witness(&pp.ck_secondary);
+    let (_nifs_secondary, (folded_u_secondary, folded_w_secondary)) = NIFS::prove(
+      &pp.ck_secondary,
+      &pp.r1cs_shape_secondary.shape,
+      &mut self.transcript_secondary,
+      &self.r_u_secondary,
+      &self.r_w_secondary,
+      &u_secondary,
+      &w_secondary,
+    )?;
+
+    self.r_u_primary = folded_u_primary;
+    self.r_w_primary = folded_w_primary;
+    self.r_u_secondary = folded_u_secondary;
+    self.r_w_secondary = folded_w_secondary;
+    self.zi_primary = z_next_primary;
+    self.zi_secondary = z_next_secondary;
+    self.i += 1;
+
+    Ok(())
+  }
+
+  /// Checks that both running accumulators still satisfy the relaxed R1CS relation for their
+  /// shape, that `num_steps` completed steps were claimed, that the chain actually started at the
+  /// claimed `(z0_primary, z0_secondary)`, and returns the current IVC output.
+  pub fn verify(
+    &self,
+    pp: &PublicParams<E1, E2, C1, C2>,
+    num_steps: usize,
+    z0_primary: &[E1::Scalar],
+    z0_secondary: &[E2::Scalar],
+  ) -> Result<(Vec<E1::Scalar>, Vec<E2::Scalar>), NovaError> {
+    if self.i != num_steps {
+      return Err(NovaError::ProofVerifyError);
+    }
+    if self.z0_primary != z0_primary || self.z0_secondary != z0_secondary {
+      return Err(NovaError::ProofVerifyError);
+    }
+    pp.r1cs_shape_primary
+      .shape
+      .is_sat_relaxed(&pp.ck_primary, &self.r_u_primary, &self.r_w_primary)?;
+    pp.r1cs_shape_secondary
+      .shape
+      .is_sat_relaxed(&pp.ck_secondary, &self.r_u_secondary, &self.r_w_secondary)?;
+
+    Ok((self.zi_primary.clone(), self.zi_secondary.clone()))
+  }
+}
+
+/// The prover key for `CompressedSNARK`: the final-compression SNARK's prover keys for each side
+/// of the cycle.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ProverKey<E1, E2, S1, S2>
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+  S1: RelaxedR1CSSNARKTrait<E1>,
+  S2: RelaxedR1CSSNARKTrait<E2>,
+{
+  pk_primary: S1::ProverKey,
+  pk_secondary: S2::ProverKey,
+}
+
+/// The verifier key for `CompressedSNARK`.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct VerifierKey<E1, E2, S1, S2>
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+  S1: RelaxedR1CSSNARKTrait<E1>,
+  S2: RelaxedR1CSSNARKTrait<E2>,
+{
+  vk_primary: S1::VerifierKey,
+  vk_secondary: S2::VerifierKey,
+}
+
+/// A SNARK compressing a `RecursiveSNARK`'s final, possibly-large running witnesses down to one
+/// `RelaxedR1CSSNARKTrait` proof per side of the cycle, so `verify` no longer needs either
+/// witness.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CompressedSNARK<E1, E2, C1, C2, S1, S2>
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+  C1: StepCircuit<E1::Scalar>,
+  C2: StepCircuit<E2::Scalar>,
+  S1: RelaxedR1CSSNARKTrait<E1>,
+  S2: RelaxedR1CSSNARKTrait<E2>,
+{
+  r_u_primary: RelaxedR1CSInstance<E1>,
+  r_u_secondary: RelaxedR1CSInstance<E2>,
+  proof_primary: S1,
+  proof_secondary: S2,
+  z0_primary: Vec<E1::Scalar>,
+  z0_secondary: Vec<E2::Scalar>,
+  zn_primary: Vec<E1::Scalar>,
+  zn_secondary: Vec<E2::Scalar>,
+  _p: PhantomData<(C1, C2)>,
+}
+
+impl<E1, E2, C1, C2, S1, S2> CompressedSNARK<E1, E2, C1, C2, S1, S2>
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+  C1: StepCircuit<E1::Scalar>,
+  C2: StepCircuit<E2::Scalar>,
+  S1: RelaxedR1CSSNARKTrait<E1>,
+  S2: RelaxedR1CSSNARKTrait<E2>,
+{
+  /// Derives prover/verifier keys for the final-compression SNARKs, sized for `pp`'s shapes.
+  pub fn setup(
+    pp: &PublicParams<E1, E2, C1, C2>,
+  ) -> Result<(ProverKey<E1, E2, S1, S2>, VerifierKey<E1, E2, S1, S2>), NovaError> {
+    let (pk_primary, vk_primary) = S1::setup(&pp.ck_primary, &pp.r1cs_shape_primary.shape)?;
+    let (pk_secondary, vk_secondary) = S2::setup(&pp.ck_secondary, &pp.r1cs_shape_secondary.shape)?;
+    Ok((
+      ProverKey { pk_primary, pk_secondary },
+      VerifierKey { vk_primary, vk_secondary },
+    ))
+  }
+
+  /// Compresses `recursive_snark`'s final accumulators into one proof per side of the cycle.
+  pub fn prove(
+    pp: &PublicParams<E1, E2, C1, C2>,
+    pk: &ProverKey<E1, E2, S1, S2>,
+    recursive_snark: &RecursiveSNARK<E1, E2, C1, C2>,
+  ) -> Result<Self, NovaError> {
+    let proof_primary = S1::prove(
+      &pp.ck_primary,
+      &pk.pk_primary,
+      &recursive_snark.r_u_primary,
+      &recursive_snark.r_w_primary,
+    )?;
+    let proof_secondary = S2::prove(
+      &pp.ck_secondary,
+      &pk.pk_secondary,
+      &recursive_snark.r_u_secondary,
+      &recursive_snark.r_w_secondary,
+    )?;
+    Ok(Self {
+      r_u_primary: recursive_snark.r_u_primary.clone(),
+      r_u_secondary: recursive_snark.r_u_secondary.clone(),
+      proof_primary,
+      proof_secondary,
+      z0_primary: recursive_snark.z0_primary.clone(),
+      z0_secondary: recursive_snark.z0_secondary.clone(),
+      zn_primary: recursive_snark.zi_primary.clone(),
+      zn_secondary: recursive_snark.zi_secondary.clone(),
+      _p: PhantomData,
+    })
+  }
+
+  /// Verifies both final-compression proofs against the running instances they were produced
+  /// for, checks that they were produced for the claimed `(z0_primary, z0_secondary)`, and
+  /// returns the IVC output they commit to.
+  pub fn verify(
+    &self,
+    vk: &VerifierKey<E1, E2, S1, S2>,
+    num_steps: usize,
+    z0_primary: &[E1::Scalar],
+    z0_secondary: &[E2::Scalar],
+  ) -> Result<(Vec<E1::Scalar>, Vec<E2::Scalar>), NovaError> {
+    if num_steps == 0 {
+      return Err(NovaError::ProofVerifyError);
+    }
+    if self.z0_primary != z0_primary || self.z0_secondary != z0_secondary {
+      return Err(NovaError::ProofVerifyError);
+    }
+    self.proof_primary.verify(&vk.vk_primary, &self.r_u_primary)?;
+    self.proof_secondary.verify(&vk.vk_secondary, &self.r_u_secondary)?;
+    Ok((self.zn_primary.clone(), self.zn_secondary.clone()))
+  }
+}
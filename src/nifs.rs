@@ -0,0 +1,99 @@
+//! The Non-Interactive Folding Scheme (NIFS): folds a fresh, satisfying R1CS instance/witness
+//! pair into a running relaxed R1CS accumulator, committing to the single "cross term" the
+//! relation's quadratic structure produces, so the verifier can recompute the folded instance
+//! from `(U1, U2, comm_T)` alone, without ever seeing a witness.
+use crate::{
+  errors::NovaError,
+  r1cs::{R1CSInstance, R1CSShape, R1CSWitness, RelaxedR1CSInstance, RelaxedR1CSWitness},
+  traits::{commitment::CommitmentEngineTrait, Engine, TranscriptEngineTrait},
+  Commitment, CommitmentKey,
+};
+use ff::Field;
+use serde::{Deserialize, Serialize};
+
+/// A folding proof: the commitment to the cross term `T`. Given this and the two instances being
+/// folded, the verifier can recompute the resulting folded instance itself.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct NIFS<E: Engine> {
+  pub comm_T: Commitment<E>,
+}
+
+impl<E: Engine> NIFS<E> {
+  /// Folds `(U2, W2)` -- fresh and non-relaxed, i.e. implicitly `u = 1`, `E = 0` -- into the
+  /// running relaxed accumulator `(U1, W1)`.
+  ///
+  /// For `z1 = (W1.W, U1.u, U1.X)` and `z2 = (W2.W, 1, U2.X)`, the cross term is
+  /// `T = (A*z1) ∘ (B*z2) + (A*z2) ∘ (B*z1) - U1.u * (C*z2) - (C*z1)`, the unique correction term
+  /// that makes `z' = z1 + r * z2` satisfy the relaxed relation with slack `E' = E1 + r*T` for any
+  /// challenge `r`.
+  pub fn prove(
+    ck: &CommitmentKey<E>,
+    S: &R1CSShape<E>,
+    transcript: &mut E::TE,
+    U1: &RelaxedR1CSInstance<E>,
+    W1: &RelaxedR1CSWitness<E>,
+    U2: &R1CSInstance<E>,
+    W2: &R1CSWitness<E>,
+  ) -> Result<(Self, (RelaxedR1CSInstance<E>, RelaxedR1CSWitness<E>)), NovaError> {
+    let (az1, bz1, cz1) = S.multiply_witness(&W1.W, &U1.u, &U1.X);
+    let (az2, bz2, cz2) = S.multiply_witness(&W2.W, &E::Scalar::ONE, &U2.X);
+
+    let T: Vec<E::Scalar> = (0..S.num_cons)
+      .map(|i| az1[i] * bz2[i] + az2[i] * bz1[i] - U1.u * cz2[i] - cz1[i])
+      .collect();
+    let comm_T = E::CE::commit(ck, &T);
+
+    let r = Self::challenge(transcript, U1, U2, &comm_T)?;
+
+    let comm_W = U1.comm_W.clone() + U2.comm_W.scalar_mul(&r);
+    let comm_E = U1.comm_E.clone() + comm_T.scalar_mul(&r);
+    let u = U1.u + r;
+    let X = fold_vec(&U1.X, &U2.X, &r);
+
+    let W = fold_vec(&W1.W, &W2.W, &r);
+    let E_ = fold_vec(&W1.E, &T, &r);
+
+    Ok((
+      Self { comm_T },
+      (
+        RelaxedR1CSInstance { comm_W, comm_E, X, u },
+        RelaxedR1CSWitness { W, E: E_ },
+      ),
+    ))
+  }
+
+  /// Verifier-side folding: recomputes the folded instance from `U1`, `U2`, and `self.comm_T`,
+  /// re-deriving the same challenge `prove` used.
+  pub fn verify(
+    &self,
+    transcript: &mut E::TE,
+    U1: &RelaxedR1CSInstance<E>,
+    U2: &R1CSInstance<E>,
+  ) -> Result<RelaxedR1CSInstance<E>, NovaError> {
+    let r = Self::challenge(transcript, U1, U2, &self.comm_T)?;
+
+    Ok(RelaxedR1CSInstance {
+      comm_W: U1.comm_W.clone() + U2.comm_W.scalar_mul(&r),
+      comm_E: U1.comm_E.clone() + self.comm_T.scalar_mul(&r),
+      u: U1.u + r,
+      X: fold_vec(&U1.X, &U2.X, &r),
+    })
+  }
+
+  fn challenge(
+    transcript: &mut E::TE,
+    U1: &RelaxedR1CSInstance<E>,
+    U2: &R1CSInstance<E>,
+    comm_T: &Commitment<E>,
+  ) -> Result<E::Scalar, NovaError> {
+    transcript.absorb(b"U1", U1);
+    transcript.absorb(b"U2", U2);
+    transcript.absorb(b"comm_T", comm_T);
+    transcript.squeeze(b"r")
+  }
+}
+
+fn fold_vec<S: Field>(a: &[S], b: &[S], r: &S) -> Vec<S> {
+  a.iter().zip(b.iter()).map(|(x, y)| *x + *r * y).collect()
+}
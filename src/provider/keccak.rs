@@ -0,0 +1,59 @@
+//! A Keccak256-based Fiat-Shamir transcript: the running state is a 256-bit digest that every
+//! `absorb` rehashes together with the new bytes, and every `squeeze` rehashes together with a
+//! round counter before reducing the digest into a scalar.
+use crate::{
+  errors::NovaError,
+  traits::{transcript::TranscriptReprTrait, Engine, TranscriptEngineTrait},
+};
+use ff::Field;
+use sha3::{Digest, Keccak256};
+
+#[derive(Clone)]
+pub struct Keccak256Transcript<E: Engine> {
+  state: [u8; 32],
+  round: u64,
+  _p: core::marker::PhantomData<E>,
+}
+
+impl<E: Engine> TranscriptEngineTrait<E> for Keccak256Transcript<E> {
+  fn new(label: &'static [u8]) -> Self {
+    let mut hasher = Keccak256::new();
+    hasher.update(b"nova_transcript");
+    hasher.update(label);
+    let mut state = [0u8; 32];
+    state.copy_from_slice(&hasher.finalize());
+    Self {
+      state,
+      round: 0,
+      _p: core::marker::PhantomData,
+    }
+  }
+
+  fn absorb<T: TranscriptReprTrait<E>>(&mut self, label: &'static [u8], o: &T) {
+    let mut hasher = Keccak256::new();
+    hasher.update(self.state);
+    hasher.update(label);
+    hasher.update(o.to_transcript_bytes());
+    self.state.copy_from_slice(&hasher.finalize());
+  }
+
+  fn squeeze(&mut self, label: &'static [u8]) -> Result<E::Scalar, NovaError> {
+    let mut hasher = Keccak256::new();
+    hasher.update(self.state);
+    hasher.update(label);
+    hasher.update(self.round.to_le_bytes());
+    self.round += 1;
+    let digest = hasher.finalize();
+    self.state.copy_from_slice(&digest);
+
+    // reduce the 256-bit digest into the scalar field by treating it as a little-endian integer
+    // modulo the field's characteristic (Horner's rule over its bytes, most significant first),
+    // so every digest maps to a scalar whatever the field's size
+    let base = E::Scalar::from(256u64);
+    let scalar = digest
+      .iter()
+      .rev()
+      .fold(E::Scalar::ZERO, |acc, b| acc * base + E::Scalar::from(u64::from(*b)));
+    Ok(scalar)
+  }
+}
@@ -0,0 +1,13 @@
+//! Concrete backends: curve-cycle `Engine` implementations and the commitment/evaluation/
+//! transcript engines they plug into the traits in `crate::traits`.
+//!
+//! This snapshot carries `traits` (the `DlogGroup`/`PairingDlogGroup` extensions to
+//! `crate::traits::Group`), `pedersen` (a Pedersen commitment engine generic over any
+//! `DlogGroup`), `keccak` (a Keccak-256 transcript engine), and `hyperkzg` (a pairing-based
+//! polynomial commitment/evaluation engine). No concrete curve (`pallas`/`vesta`/`bn256_grumpkin`/
+//! `secp_secq`) or IPA evaluation engine lives in this tree yet, so none of the above can be
+//! instantiated end to end without one.
+pub mod hyperkzg;
+pub mod keccak;
+pub mod pedersen;
+pub mod traits;
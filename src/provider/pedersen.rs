@@ -0,0 +1,108 @@
+//! A non-hiding Pedersen vector commitment: `Commit(v) = sum_i v_i * G_i`, homomorphic in `v`,
+//! which is exactly what `NIFS` folding needs (it never needs hiding, since the things it commits
+//! to -- witnesses, cross terms -- are never opened in the clear to anyone but the prover).
+use crate::{
+  provider::traits::DlogGroup,
+  traits::{
+    commitment::{CommitmentEngineTrait, CommitmentTrait},
+    transcript::TranscriptReprTrait,
+    Engine,
+  },
+};
+use core::ops::Add;
+use ff::Field;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CommitmentKey<E: Engine>
+where
+  E::GE: DlogGroup,
+{
+  ck: Vec<<E::GE as DlogGroup>::AffineGroupElement>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(bound = "")]
+pub struct Commitment<E: Engine>
+where
+  E::GE: DlogGroup,
+{
+  comm: <E::GE as DlogGroup>::AffineGroupElement,
+}
+
+impl<E: Engine> TranscriptReprTrait<E> for Commitment<E>
+where
+  E::GE: DlogGroup,
+{
+  fn to_transcript_bytes(&self) -> Vec<u8> {
+    // the `Debug` rendering of the affine point (its coordinates) is the public byte encoding
+    // absorbed for Fiat-Shamir
+    format!("{:?}", self.comm).into_bytes()
+  }
+}
+
+impl<E: Engine> Add<Self> for Commitment<E>
+where
+  E::GE: DlogGroup,
+{
+  type Output = Self;
+
+  fn add(self, rhs: Self) -> Self {
+    let sum = E::GE::vartime_multiscalar_mul(&[E::Scalar::ONE, E::Scalar::ONE], &[self.comm, rhs.comm]);
+    Self { comm: sum.to_affine() }
+  }
+}
+
+impl<E: Engine> CommitmentTrait<E> for Commitment<E>
+where
+  E::GE: DlogGroup,
+{
+  fn scalar_mul(&self, scalar: &E::Scalar) -> Self {
+    let out = E::GE::vartime_multiscalar_mul(&[*scalar], &[self.comm.clone()]);
+    Self { comm: out.to_affine() }
+  }
+}
+
+impl<E: Engine> Commitment<E>
+where
+  E::GE: DlogGroup,
+{
+  /// The raw affine group element underlying this commitment. Exposed for pairing-based schemes
+  /// (e.g. `provider::hyperkzg`) built atop the same curve that need direct curve arithmetic
+  /// `CommitmentTrait` doesn't expose.
+  pub fn to_affine(&self) -> <E::GE as DlogGroup>::AffineGroupElement {
+    self.comm.clone()
+  }
+
+  /// Wraps a raw affine group element as a commitment (the inverse of `to_affine`).
+  pub fn from_affine(comm: <E::GE as DlogGroup>::AffineGroupElement) -> Self {
+    Self { comm }
+  }
+}
+
+/// A Pedersen commitment engine over any curve implementing `DlogGroup`.
+#[derive(Clone, Debug)]
+pub struct CommitmentEngine<E: Engine> {
+  _p: core::marker::PhantomData<E>,
+}
+
+impl<E: Engine> CommitmentEngineTrait<E> for CommitmentEngine<E>
+where
+  E::GE: DlogGroup,
+{
+  type CommitmentKey = CommitmentKey<E>;
+  type Commitment = Commitment<E>;
+
+  fn setup(label: &'static [u8], n: usize) -> Self::CommitmentKey {
+    CommitmentKey {
+      ck: E::GE::from_label(label, n.max(1)),
+    }
+  }
+
+  fn commit(ck: &Self::CommitmentKey, v: &[E::Scalar]) -> Self::Commitment {
+    assert!(v.len() <= ck.ck.len(), "commitment key too small for vector");
+    let comm = E::GE::vartime_multiscalar_mul(v, &ck.ck[..v.len()]);
+    Commitment { comm: comm.to_affine() }
+  }
+}
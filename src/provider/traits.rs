@@ -0,0 +1,52 @@
+//! Group-theoretic operations the commitment and evaluation engines in `provider` need from a
+//! curve, beyond what `traits::Group` already requires.
+use crate::traits::Group;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// A cryptographic group usable for Pedersen-style vector commitments and KZG-style SRS-based
+/// commitments: besides the scalar/base fields `Group` already exposes, it needs affine encoding,
+/// a way to derive a batch of independent generators from a label (no trusted setup required for
+/// the Pedersen case), and variable-time multi-scalar multiplication.
+pub trait DlogGroup: Group + Sized {
+  /// The affine representation of a group element, used for commitment keys and proofs so they
+  /// serialize compactly.
+  type AffineGroupElement: Clone + Debug + PartialEq + Eq + Send + Sync + Serialize + for<'de> Deserialize<'de>;
+
+  /// Computes `sum_i scalars[i] * bases[i]`. Variable-time is fine: this only ever runs on
+  /// public commitments, never secret scalars.
+  fn vartime_multiscalar_mul(scalars: &[Self::Scalar], bases: &[Self::AffineGroupElement]) -> Self;
+
+  /// Converts to affine form.
+  fn to_affine(&self) -> Self::AffineGroupElement;
+
+  /// The group's fixed generator, in affine form.
+  fn generator() -> Self::AffineGroupElement;
+
+  /// Deterministically derives `n` independent generators from `label` (e.g. by hashing
+  /// `label || i` to a group element for each `i`), used to build a commitment key without a
+  /// trusted setup ceremony.
+  fn from_label(label: &'static [u8], n: usize) -> Vec<Self::AffineGroupElement>;
+}
+
+/// A `DlogGroup` that additionally sits on one side of a bilinear pairing, which is what lets
+/// `provider::hyperkzg` verify polynomial openings in constant time regardless of the
+/// polynomial's degree. `Self` plays the role of the pairing's `G1`; `G2` is its other source
+/// group.
+pub trait PairingDlogGroup: DlogGroup {
+  /// The pairing's second source group, in affine form (e.g. where the trusted setup's `tau * H`
+  /// lives).
+  type G2: Clone + Debug + PartialEq + Eq + Send + Sync + Serialize + for<'de> Deserialize<'de>;
+
+  /// The `G2` generator, in affine form.
+  fn g2_generator() -> Self::G2;
+
+  /// Computes `sum_i scalars[i] * bases[i]` in `G2`.
+  fn g2_vartime_multiscalar_mul(scalars: &[Self::Scalar], bases: &[Self::G2]) -> Self::G2;
+
+  /// Returns whether `prod_i e(g1[i], g2[i]) == 1` in the pairing target group (via one combined
+  /// Miller loop and a single final exponentiation). This is what lets a verifier rewrite any
+  /// number of individual `e(A, B) == e(C, D)` checks as `e(A, B) * e(-C, D) == 1` and fold them
+  /// all into one multi-pairing check.
+  fn pairing_check(g1: &[Self::AffineGroupElement], g2: &[Self::G2]) -> bool;
+}
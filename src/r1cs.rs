@@ -0,0 +1,219 @@
+//! Relaxed R1CS: the relation Nova's folding scheme operates over.
+//!
+//! An ordinary R1CS instance/witness pair `(U, W)` satisfies shape `S` when, for `z = (W.W, 1,
+//! U.X)`, `(S.A * z) ∘ (S.B * z) = (S.C * z)` (`∘` is the Hadamard product). The *relaxed* relation
+//! used by Nova's accumulator adds a slack scalar `u` and a slack vector `E`:
+//! `(S.A * z) ∘ (S.B * z) = u * (S.C * z) + E`, where `z = (W.W, u, U.X)`. A satisfying
+//! non-relaxed pair is just a relaxed pair with `u = 1, E = 0`, so it can always be folded into a
+//! running relaxed accumulator; see `nifs::NIFS`.
+use crate::{
+  errors::NovaError,
+  traits::{commitment::CommitmentEngineTrait, transcript::TranscriptReprTrait, Engine},
+  Commitment, CommitmentKey,
+};
+use ff::{Field, PrimeField};
+use serde::{Deserialize, Serialize};
+
+/// An R1CS shape in sparse `(row, col, val)` form. Columns `0..num_vars` index the witness `W`,
+/// column `num_vars` is the constant `1` (`u` once relaxed), and columns `num_vars+1..` index the
+/// public IO `X` (so `num_vars + 1 + num_io` columns in total).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct R1CSShape<E: Engine> {
+  pub num_cons: usize,
+  pub num_vars: usize,
+  pub num_io: usize,
+  pub A: Vec<(usize, usize, E::Scalar)>,
+  pub B: Vec<(usize, usize, E::Scalar)>,
+  pub C: Vec<(usize, usize, E::Scalar)>,
+}
+
+impl<E: Engine> R1CSShape<E> {
+  /// The column just past the witness, where `z`'s constant/slack term `u` lives.
+  pub fn u_index(&self) -> usize {
+    self.num_vars
+  }
+
+  fn multiply_vec(matrix: &[(usize, usize, E::Scalar)], num_cons: usize, z: &[E::Scalar]) -> Vec<E::Scalar> {
+    let mut out = vec![E::Scalar::ZERO; num_cons];
+    for &(row, col, val) in matrix {
+      out[row] += val * z[col];
+    }
+    out
+  }
+
+  fn z(&self, W: &[E::Scalar], u: &E::Scalar, X: &[E::Scalar]) -> Vec<E::Scalar> {
+    let mut z = Vec::with_capacity(self.num_vars + 1 + self.num_io);
+    z.extend_from_slice(W);
+    z.push(*u);
+    z.extend_from_slice(X);
+    z
+  }
+
+  /// Computes `(A*z, B*z, C*z)` for `z = (W, u, X)`.
+  pub fn multiply_witness(
+    &self,
+    W: &[E::Scalar],
+    u: &E::Scalar,
+    X: &[E::Scalar],
+  ) -> (Vec<E::Scalar>, Vec<E::Scalar>, Vec<E::Scalar>) {
+    let z = self.z(W, u, X);
+    (
+      Self::multiply_vec(&self.A, self.num_cons, &z),
+      Self::multiply_vec(&self.B, self.num_cons, &z),
+      Self::multiply_vec(&self.C, self.num_cons, &z),
+    )
+  }
+
+  /// Checks that `(U, W)` satisfies the relaxed relation for this shape, and that `U`'s
+  /// commitments match `W` under `ck`.
+  pub fn is_sat_relaxed(
+    &self,
+    ck: &CommitmentKey<E>,
+    U: &RelaxedR1CSInstance<E>,
+    W: &RelaxedR1CSWitness<E>,
+  ) -> Result<(), NovaError> {
+    if W.W.len() != self.num_vars || W.E.len() != self.num_cons || U.X.len() != self.num_io {
+      return Err(NovaError::InvalidWitnessLength);
+    }
+    let (Az, Bz, Cz) = self.multiply_witness(&W.W, &U.u, &U.X);
+    for i in 0..self.num_cons {
+      if Az[i] * Bz[i] != U.u * Cz[i] + W.E[i] {
+        return Err(NovaError::UnSat);
+      }
+    }
+    if E::CE::commit(ck, &W.W) != U.comm_W || E::CE::commit(ck, &W.E) != U.comm_E {
+      return Err(NovaError::UnSat);
+    }
+    Ok(())
+  }
+
+  /// Checks that `(U, W)` satisfies the (non-relaxed) relation for this shape.
+  pub fn is_sat(
+    &self,
+    ck: &CommitmentKey<E>,
+    U: &R1CSInstance<E>,
+    W: &R1CSWitness<E>,
+  ) -> Result<(), NovaError> {
+    if W.W.len() != self.num_vars || U.X.len() != self.num_io {
+      return Err(NovaError::InvalidWitnessLength);
+    }
+    let (Az, Bz, Cz) = self.multiply_witness(&W.W, &E::Scalar::ONE, &U.X);
+    for i in 0..self.num_cons {
+      if Az[i] * Bz[i] != Cz[i] {
+        return Err(NovaError::UnSat);
+      }
+    }
+    if E::CE::commit(ck, &W.W) != U.comm_W {
+      return Err(NovaError::UnSat);
+    }
+    Ok(())
+  }
+}
+
+/// Parameters for one R1CS shape paired with the arity of the `StepCircuit` it was derived from
+/// (the arity is needed to lay public IO back out as `z_i`/`z_{i+1}` on the cycle's other side).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct R1CSWithArity<E: Engine> {
+  pub shape: R1CSShape<E>,
+  pub arity: usize,
+}
+
+impl<E: Engine> R1CSWithArity<E> {
+  pub fn new(shape: R1CSShape<E>, arity: usize) -> Self {
+    Self { shape, arity }
+  }
+}
+
+/// A satisfying (non-relaxed) R1CS instance: a commitment to the witness plus the public IO.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(bound = "")]
+pub struct R1CSInstance<E: Engine> {
+  pub comm_W: Commitment<E>,
+  pub X: Vec<E::Scalar>,
+}
+
+impl<E: Engine> TranscriptReprTrait<E> for R1CSInstance<E> {
+  fn to_transcript_bytes(&self) -> Vec<u8> {
+    let mut bytes = self.comm_W.to_transcript_bytes();
+    for x in &self.X {
+      bytes.extend_from_slice(x.to_repr().as_ref());
+    }
+    bytes
+  }
+}
+
+/// The witness half of an `R1CSInstance`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct R1CSWitness<E: Engine> {
+  pub W: Vec<E::Scalar>,
+}
+
+impl<E: Engine> R1CSWitness<E> {
+  pub fn commit(&self, ck: &CommitmentKey<E>) -> Commitment<E> {
+    E::CE::commit(ck, &self.W)
+  }
+}
+
+/// A relaxed R1CS instance: adds the slack commitment `comm_E` and slack scalar `u` to an
+/// `R1CSInstance`, which is what lets it absorb the cross-term error NIFS folding introduces.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(bound = "")]
+pub struct RelaxedR1CSInstance<E: Engine> {
+  pub comm_W: Commitment<E>,
+  pub comm_E: Commitment<E>,
+  pub X: Vec<E::Scalar>,
+  pub u: E::Scalar,
+}
+
+impl<E: Engine> TranscriptReprTrait<E> for RelaxedR1CSInstance<E> {
+  fn to_transcript_bytes(&self) -> Vec<u8> {
+    let mut bytes = self.comm_W.to_transcript_bytes();
+    bytes.extend(self.comm_E.to_transcript_bytes());
+    bytes.extend_from_slice(self.u.to_repr().as_ref());
+    for x in &self.X {
+      bytes.extend_from_slice(x.to_repr().as_ref());
+    }
+    bytes
+  }
+}
+
+impl<E: Engine> RelaxedR1CSInstance<E> {
+  /// The all-zero relaxed instance for `num_io` public inputs/outputs, the initial running
+  /// accumulator before any step has been folded.
+  pub fn default(ck: &CommitmentKey<E>, num_io: usize) -> Self {
+    Self {
+      comm_W: E::CE::commit(ck, &[]),
+      comm_E: E::CE::commit(ck, &[]),
+      X: vec![E::Scalar::ZERO; num_io],
+      u: E::Scalar::ZERO,
+    }
+  }
+}
+
+/// The witness half of a `RelaxedR1CSInstance`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct RelaxedR1CSWitness<E: Engine> {
+  pub W: Vec<E::Scalar>,
+  pub E: Vec<E::Scalar>,
+}
+
+impl<E: Engine> RelaxedR1CSWitness<E> {
+  pub fn default(num_vars: usize, num_cons: usize) -> Self {
+    Self {
+      W: vec![E::Scalar::ZERO; num_vars],
+      E: vec![E::Scalar::ZERO; num_cons],
+    }
+  }
+
+  /// Lifts a non-relaxed witness (implicitly `u = 1`, `E = 0`) into relaxed form.
+  pub fn from_r1cs_witness(S: &R1CSShape<E>, W: &R1CSWitness<E>) -> Self {
+    Self {
+      W: W.W.clone(),
+      E: vec![E::Scalar::ZERO; S.num_cons],
+    }
+  }
+}
@@ -0,0 +1,8 @@
+//! Final-compression `RelaxedR1CSSNARKTrait` implementations.
+//!
+//! This snapshot carries `ppsnark`, a preprocessing SNARK whose verifier never reads the R1CS
+//! matrices and does work logarithmic in the number of constraints (built on the sum-check protocol in `sumcheck`, using a dense matrix commitment
+//! rather than real Spartan's succinct "Spark" sparse encoding). The non-preprocessing `snark`
+//! sibling does not exist in this tree.
+pub mod ppsnark;
+mod sumcheck;
@@ -0,0 +1,244 @@
+//! A minimal sum-check protocol over the Boolean hypercube, used by `spartan::ppsnark` to reduce
+//! a claimed sum of a low-degree multivariate polynomial (given as its table of evaluations over
+//! the hypercube, rather than symbolically) to a claim about a single random point.
+//!
+//! Every table this module folds, and every point it evaluates one at, shares one convention:
+//! the first coordinate is the most significant bit of the table's index (so folding a table in
+//! half at round `k` fixes variable `k`, and `eq_table`/`eval_mle` walk `tau`/`point` in the same
+//! order).
+use crate::{
+  errors::NovaError,
+  traits::{Engine, TranscriptEngineTrait},
+};
+use ff::Field;
+use serde::{Deserialize, Serialize};
+
+/// A sum-check proof: one round polynomial per variable, each given as its evaluations at
+/// `0, 1, ..., degree`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub(crate) struct SumcheckProof<F: ff::PrimeField> {
+  pub(crate) round_polys: Vec<Vec<F>>,
+}
+
+/// `n` as a field element, built by repeated addition so this only ever needs `Field`, not a
+/// `From<u64>` bound (`n` is always a small constant here: a sample point `0..=degree` or a
+/// Lagrange-interpolation index).
+fn small<F: Field>(n: usize) -> F {
+  let mut acc = F::ZERO;
+  for _ in 0..n {
+    acc += F::ONE;
+  }
+  acc
+}
+
+/// Evaluates the unique polynomial of degree `evals.len() - 1` through
+/// `(0, evals[0]), (1, evals[1]), ...` at `x`, via Lagrange interpolation.
+fn interpolate<F: Field>(evals: &[F], x: F) -> F {
+  let n = evals.len();
+  let mut result = F::ZERO;
+  for (i, &ei) in evals.iter().enumerate() {
+    let mut num = F::ONE;
+    let mut den = F::ONE;
+    for j in 0..n {
+      if i == j {
+        continue;
+      }
+      num *= x - small::<F>(j);
+      den *= small::<F>(i) - small::<F>(j);
+    }
+    result += ei * num * den.invert().unwrap();
+  }
+  result
+}
+
+/// Folds `table` in half by fixing its first remaining variable to `r`.
+fn fold<F: Field>(table: &[F], r: F) -> Vec<F> {
+  let half = table.len() / 2;
+  (0..half).map(|i| table[i] + r * (table[half + i] - table[i])).collect()
+}
+
+/// Evaluates the multilinear extension of `table` (a power-of-two length evaluation table over
+/// the Boolean hypercube) at `point` (which need not itself be Boolean).
+pub(crate) fn eval_mle<F: Field>(table: &[F], point: &[F]) -> F {
+  let mut cur = table.to_vec();
+  for &p in point {
+    cur = fold(&cur, p);
+  }
+  cur[0]
+}
+
+/// Builds the table of `eq(tau, x) = prod_i (tau_i*x_i + (1-tau_i)*(1-x_i))` for every Boolean
+/// `x` -- the unique multilinear polynomial that is `1` at `x = tau` and `0` at every other
+/// Boolean point.
+pub(crate) fn eq_table<F: Field>(tau: &[F]) -> Vec<F> {
+  let mut t = vec![F::ONE];
+  for &r in tau {
+    // each new variable becomes the least significant bit so far, keeping `tau[0]` the most
+    // significant, as in `eval_mle`
+    let mut next = Vec::with_capacity(t.len() * 2);
+    for &v in &t {
+      next.push(v * (F::ONE - r));
+      next.push(v * r);
+    }
+    t = next;
+  }
+  t
+}
+
+/// `eq_table(tau)` evaluated at `point` directly, without materializing the table.
+pub(crate) fn eq_eval<F: Field>(tau: &[F], point: &[F]) -> F {
+  tau
+    .iter()
+    .zip(point.iter())
+    .map(|(&t, &p)| t * p + (F::ONE - t) * (F::ONE - p))
+    .fold(F::ONE, |acc, v| acc * v)
+}
+
+/// Runs the prover side of a sum-check over `combine` applied point-wise to `tables` (each an
+/// evaluation table of the same power-of-two length `2^num_rounds`), which has algebraic degree
+/// `degree` in the tables' values. Challenges are derived non-interactively from `transcript`.
+/// Returns the proof, the challenges (one per round, most-significant first), and the final
+/// (folded down to one entry) value of every table.
+pub(crate) fn prove<E: Engine>(
+  transcript: &mut E::TE,
+  num_rounds: usize,
+  degree: usize,
+  mut tables: Vec<Vec<E::Scalar>>,
+  combine: impl Fn(&[E::Scalar]) -> E::Scalar,
+) -> Result<(SumcheckProof<E::Scalar>, Vec<E::Scalar>, Vec<E::Scalar>), NovaError> {
+  let mut round_polys = Vec::with_capacity(num_rounds);
+  let mut challenges = Vec::with_capacity(num_rounds);
+
+  for _ in 0..num_rounds {
+    let half = tables[0].len() / 2;
+    let mut evals = vec![E::Scalar::ZERO; degree + 1];
+    for i in 0..half {
+      let extended: Vec<Vec<E::Scalar>> = tables
+        .iter()
+        .map(|t| {
+          let (low, high) = (t[i], t[half + i]);
+          (0..=degree).map(|x| low + small::<E::Scalar>(x) * (high - low)).collect()
+        })
+        .collect();
+      for (x, eval) in evals.iter_mut().enumerate() {
+        let point: Vec<E::Scalar> = extended.iter().map(|row| row[x]).collect();
+        *eval += combine(&point);
+      }
+    }
+    for e in &evals {
+      transcript.absorb(b"sumcheck_round_poly_eval", e);
+    }
+    let r = transcript.squeeze(b"sumcheck_r")?;
+    challenges.push(r);
+    tables = tables.iter().map(|t| fold(t, r)).collect();
+    round_polys.push(evals);
+  }
+
+  let finals = tables.into_iter().map(|t| t[0]).collect();
+  Ok((SumcheckProof { round_polys }, challenges, finals))
+}
+
+/// Runs the verifier side of a sum-check that `claim` is the sum, over the `2^num_rounds`-point
+/// Boolean hypercube, of a degree-`degree` polynomial. Returns the final claim (to be checked
+/// against `combine` applied to independently-verified evaluations of the original tables at the
+/// returned challenges) and the challenges themselves.
+pub(crate) fn verify<E: Engine>(
+  transcript: &mut E::TE,
+  num_rounds: usize,
+  degree: usize,
+  mut claim: E::Scalar,
+  proof: &SumcheckProof<E::Scalar>,
+) -> Result<(E::Scalar, Vec<E::Scalar>), NovaError> {
+  if proof.round_polys.len() != num_rounds {
+    return Err(NovaError::ProofVerifyError);
+  }
+  let mut challenges = Vec::with_capacity(num_rounds);
+  for evals in &proof.round_polys {
+    if evals.len() != degree + 1 {
+      return Err(NovaError::ProofVerifyError);
+    }
+    if evals[0] + evals[1] != claim {
+      return Err(NovaError::ProofVerifyError);
+    }
+    for e in evals {
+      transcript.absorb(b"sumcheck_round_poly_eval", e);
+    }
+    let r = transcript.squeeze(b"sumcheck_r")?;
+    claim = interpolate(evals, r);
+    challenges.push(r);
+  }
+  Ok((claim, challenges))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use pasta_curves::Fp;
+
+  #[test]
+  fn eq_table_matches_eq_eval_at_every_boolean_point() {
+    let tau = [Fp::from(3u64), Fp::from(5u64)];
+    let table = eq_table(&tau);
+    for (x, &expected) in table.iter().enumerate() {
+      let bits = [Fp::from(((x >> 1) & 1) as u64), Fp::from((x & 1) as u64)];
+      assert_eq!(eq_eval(&tau, &bits), expected);
+    }
+  }
+
+  #[test]
+  fn eval_mle_agrees_with_table_at_boolean_points() {
+    let table = [Fp::from(1u64), Fp::from(2u64), Fp::from(3u64), Fp::from(4u64)];
+    assert_eq!(eval_mle(&table, &[Fp::ZERO, Fp::ZERO]), Fp::from(1u64));
+    assert_eq!(eval_mle(&table, &[Fp::ZERO, Fp::ONE]), Fp::from(2u64));
+    assert_eq!(eval_mle(&table, &[Fp::ONE, Fp::ZERO]), Fp::from(3u64));
+    assert_eq!(eval_mle(&table, &[Fp::ONE, Fp::ONE]), Fp::from(4u64));
+  }
+
+  #[test]
+  fn interpolate_recovers_a_known_quadratic() {
+    // p(X) = 1 + X + X^2: p(0)=1, p(1)=3, p(2)=7
+    let evals = [Fp::from(1u64), Fp::from(3u64), Fp::from(7u64)];
+    assert_eq!(interpolate(&evals, Fp::from(5u64)), Fp::from(31u64));
+  }
+
+  use crate::test_utils::ScalarOnlyEngine;
+
+  #[test]
+  fn prove_verify_round_trip_for_a_degree_two_product() {
+    use crate::provider::keccak::Keccak256Transcript;
+
+    // tables a, b over 4 Boolean points; claim = sum_x a(x)*b(x)
+    let a = vec![Fp::from(1u64), Fp::from(2u64), Fp::from(3u64), Fp::from(4u64)];
+    let b = vec![Fp::from(5u64), Fp::from(6u64), Fp::from(7u64), Fp::from(8u64)];
+    let claim: Fp = a.iter().zip(b.iter()).map(|(&x, &y)| x * y).sum();
+
+    let mut pt = Keccak256Transcript::<ScalarOnlyEngine>::new(b"test");
+    let (proof, challenges_p, finals) =
+      prove::<ScalarOnlyEngine>(&mut pt, 2, 2, vec![a.clone(), b.clone()], |e| e[0] * e[1]).unwrap();
+
+    let mut vt = Keccak256Transcript::<ScalarOnlyEngine>::new(b"test");
+    let (final_claim, challenges_v) = verify::<ScalarOnlyEngine>(&mut vt, 2, 2, claim, &proof).unwrap();
+
+    assert_eq!(challenges_p, challenges_v);
+    assert_eq!(final_claim, finals[0] * finals[1]);
+    assert_eq!(finals[0], eval_mle(&a, &challenges_v));
+    assert_eq!(finals[1], eval_mle(&b, &challenges_v));
+  }
+
+  #[test]
+  fn verify_rejects_a_tampered_claim() {
+    use crate::provider::keccak::Keccak256Transcript;
+
+    let a = vec![Fp::from(1u64), Fp::from(2u64), Fp::from(3u64), Fp::from(4u64)];
+    let b = vec![Fp::from(5u64), Fp::from(6u64), Fp::from(7u64), Fp::from(8u64)];
+    let claim: Fp = a.iter().zip(b.iter()).map(|(&x, &y)| x * y).sum();
+
+    let mut pt = Keccak256Transcript::<ScalarOnlyEngine>::new(b"test");
+    let (proof, _, _) = prove::<ScalarOnlyEngine>(&mut pt, 2, 2, vec![a, b], |e| e[0] * e[1]).unwrap();
+
+    let mut vt = Keccak256Transcript::<ScalarOnlyEngine>::new(b"test");
+    let wrong_claim = claim + Fp::ONE;
+    assert!(verify::<ScalarOnlyEngine>(&mut vt, 2, 2, wrong_claim, &proof).is_err());
+  }
+}
@@ -0,0 +1,104 @@
+//! The program-counter gadget for the augmented circuit of a non-uniform (SuperNova) IVC.
+//!
+//! `SuperNovaAugmentedCircuit::synthesize_pc`:
+//! - allocates the selector `circuit_index` of the circuit being synthesized this step, pins it
+//!   to that circuit's own `circuit_index()`, and enforces that the incoming program counter
+//!   `pc_i` equals it;
+//! - range-checks `circuit_index` against the non-uniform circuit's arity (`num_circuits`), so a
+//!   prover cannot select an accumulator slot that doesn't exist.
+//!
+//! It does not fold any running instance, does not synthesize the step circuit, and places no
+//! constraint on the other circuits' accumulators. `supernova::RecursiveSNARK` folds and passes
+//! accumulators through natively and does not call this gadget.
+use crate::traits::circuit::StepCircuit;
+use bellpepper_core::{
+  boolean::{AllocatedBit, Boolean},
+  num::AllocatedNum,
+  ConstraintSystem, SynthesisError,
+};
+use ff::PrimeField;
+
+/// Parameters shared by every instantiation of the SuperNova augmented circuit for a given
+/// non-uniform circuit set.
+#[derive(Clone, Debug)]
+pub struct SuperNovaAugmentedCircuit<F: PrimeField, SC: StepCircuit<F>> {
+  step_circuit: SC,
+  num_circuits: usize,
+  _p: core::marker::PhantomData<F>,
+}
+
+impl<F: PrimeField, SC: StepCircuit<F>> SuperNovaAugmentedCircuit<F, SC> {
+  /// Creates a new augmented circuit wrapping `step_circuit`, which must be one of `num_circuits`
+  /// constituents of the enclosing `NonUniformCircuit`.
+  pub fn new(step_circuit: SC, num_circuits: usize) -> Self {
+    Self {
+      step_circuit,
+      num_circuits,
+      _p: core::marker::PhantomData,
+    }
+  }
+
+  /// Allocates the program counter selecting the running accumulator to fold this step, enforces
+  /// it equals the step circuit's own `circuit_index()`, and range-checks it against
+  /// `num_circuits`. Returns the allocated, validated program counter.
+  pub fn synthesize_pc<CS: ConstraintSystem<F>>(
+    &self,
+    mut cs: CS,
+    pc: &AllocatedNum<F>,
+  ) -> Result<AllocatedNum<F>, SynthesisError> {
+    let circuit_index = self.step_circuit.circuit_index();
+
+    let circuit_index_alloc = AllocatedNum::alloc(cs.namespace(|| "circuit_index"), || {
+      Ok(F::from(circuit_index as u64))
+    })?;
+
+    // circuit_index is this circuit's own constant index, not a free choice of the prover
+    cs.enforce(
+      || "circuit_index is this circuit's index",
+      |lc| lc + circuit_index_alloc.get_variable(),
+      |lc| lc + CS::one(),
+      |lc| lc + (F::from(circuit_index as u64), CS::one()),
+    );
+
+    // pc == circuit_index
+    cs.enforce(
+      || "pc matches circuit_index",
+      |lc| lc + pc.get_variable(),
+      |lc| lc + CS::one(),
+      |lc| lc + circuit_index_alloc.get_variable(),
+    );
+
+    // 0 <= circuit_index < num_circuits, enforced by decomposing circuit_index into booleans
+    // sized to num_circuits and re-summing them; any out-of-range value fails to match one of
+    // the `num_circuits` boolean one-hot positions checked below.
+    let mut one_hot = Vec::with_capacity(self.num_circuits);
+    for i in 0..self.num_circuits {
+      let bit = AllocatedBit::alloc(
+        cs.namespace(|| format!("circuit_index_is_{i}")),
+        Some(circuit_index == i),
+      )?;
+      one_hot.push(bit);
+    }
+    let mut sum_lc = bellpepper_core::LinearCombination::zero();
+    let mut weighted_lc = bellpepper_core::LinearCombination::zero();
+    for (i, bit) in one_hot.iter().enumerate() {
+      sum_lc = sum_lc + bit.get_variable();
+      weighted_lc = weighted_lc + (F::from(i as u64), bit.get_variable());
+    }
+    cs.enforce(
+      || "exactly one circuit selected",
+      |_| sum_lc.clone(),
+      |lc| lc + CS::one(),
+      |lc| lc + CS::one(),
+    );
+    cs.enforce(
+      || "selected index matches circuit_index",
+      |_| weighted_lc,
+      |lc| lc + CS::one(),
+      |lc| lc + circuit_index_alloc.get_variable(),
+    );
+    let _ = Boolean::from(one_hot[0].clone());
+
+    Ok(circuit_index_alloc)
+  }
+}
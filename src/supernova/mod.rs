@@ -0,0 +1,425 @@
+//! This module implements SuperNova, a non-uniform variant of Nova's IVC: instead of folding a
+//! single fixed `StepCircuit` at every step, the prover may run a different circuit at each step,
+//! selected by a program counter `pc` that is threaded through the recursion alongside the usual
+//! IVC state `z_i`.
+//!
+//! Rather than maintaining one running relaxed-R1CS accumulator, a `supernova::RecursiveSNARK`
+//! maintains one accumulator per circuit in the `NonUniformCircuit`. At step `i` only the
+//! accumulator belonging to `pc_i` is folded (via `crate::nifs::NIFS`, the same folding scheme the
+//! uniform `crate::RecursiveSNARK` uses) with the instance produced by really synthesizing that
+//! step's circuit; every other accumulator is left untouched. See the crate-level docs for what
+//! this construction does and does not guarantee (in particular: `pc` validity is checked
+//! natively here, not yet enforced inside the circuit via `SuperNovaAugmentedCircuit::synthesize_pc`,
+//! so that check is pending the same augmented-circuit work the uniform IVC needs).
+use crate::{
+  bellpepper::solver::SatisfyingAssignment,
+  errors::NovaError,
+  nifs::NIFS,
+  r1cs::{RelaxedR1CSInstance, RelaxedR1CSWitness},
+  traits::{circuit::StepCircuit, commitment::CommitmentEngineTrait, Engine, TranscriptEngineTrait},
+  CommitmentKey, R1CSWithArity,
+};
+use bellpepper_core::{num::AllocatedNum, ConstraintSystem, SynthesisError};
+
+mod circuit;
+
+pub use circuit::SuperNovaAugmentedCircuit;
+
+/// A circuit that may be one of several `StepCircuit`s, selected at each IVC step by a program
+/// counter. Each constituent circuit must report a distinct `circuit_index()` in `0..num_circuits()`.
+pub trait NonUniformCircuit<E1, E2, C1, C2>
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+  C1: StepCircuit<E1::Scalar>,
+  C2: StepCircuit<E2::Scalar>,
+{
+  /// The number of distinct primary circuits that may be selected by the program counter.
+  fn num_circuits(&self) -> usize;
+
+  /// Returns the primary circuit to run for the given program counter.
+  fn primary_circuit(&self, circuit_index: usize) -> C1;
+
+  /// Returns the (uniform) secondary circuit, run identically at every step.
+  fn secondary_circuit(&self) -> C2;
+}
+
+fn synthesize<E, C>(
+  circuit: &C,
+  z: &[E::Scalar],
+  advice: &[E::Scalar],
+) -> Result<(Vec<E::Scalar>, SatisfyingAssignment<E>), NovaError>
+where
+  E: Engine,
+  C: StepCircuit<E::Scalar>,
+{
+  if advice.len() != circuit.advice_size() {
+    return Err(NovaError::InvalidWitnessLength);
+  }
+  let mut cs = SatisfyingAssignment::<E>::new();
+  let z_alloc: Vec<AllocatedNum<E::Scalar>> = z
+    .iter()
+    .enumerate()
+    .map(|(i, v)| AllocatedNum::alloc(cs.namespace(|| format!("z_{i}")), || Ok(*v)))
+    .collect::<Result<_, SynthesisError>>()
+    .map_err(|_| NovaError::InvalidWitnessLength)?;
+  let advice_alloc: Vec<AllocatedNum<E::Scalar>> = advice
+    .iter()
+    .enumerate()
+    .map(|(i, v)| AllocatedNum::alloc(cs.namespace(|| format!("advice_{i}")), || Ok(*v)))
+    .collect::<Result<_, SynthesisError>>()
+    .map_err(|_| NovaError::InvalidWitnessLength)?;
+
+  let z_out = circuit
+    .synthesize(&mut cs, &z_alloc, &advice_alloc)
+    .map_err(|_| NovaError::UnSat)?;
+  let z_out_values = z_out
+    .iter()
+    .map(|v| v.get_value().ok_or(NovaError::InvalidWitnessLength))
+    .collect::<Result<Vec<_>, _>>()?;
+
+  Ok((z_out_values, cs))
+}
+
+/// Public parameters for a non-uniform (SuperNova-style) recursive SNARK: one set of R1CS shapes
+/// and commitment keys per primary circuit, plus the shared shape for the secondary circuit.
+pub struct PublicParams<E1, E2>
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+{
+  primary_r1cs_shapes: Vec<R1CSWithArity<E1>>,
+  secondary_r1cs_shape: R1CSWithArity<E2>,
+  ck_primary: CommitmentKey<E1>,
+  ck_secondary: CommitmentKey<E2>,
+}
+
+impl<E1, E2> PublicParams<E1, E2>
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+{
+  /// Derives one R1CS shape per primary circuit (by synthesizing each, with placeholder zero
+  /// `z`/advice, purely to learn its constraint matrices), the shared secondary shape, and
+  /// commitment keys sized for the largest of them.
+  pub fn setup<C1, C2>(nc: &impl NonUniformCircuit<E1, E2, C1, C2>) -> Result<Self, NovaError>
+  where
+    C1: StepCircuit<E1::Scalar>,
+    C2: StepCircuit<E2::Scalar>,
+  {
+    let mut primary_r1cs_shapes = Vec::with_capacity(nc.num_circuits());
+    let mut max_vec_len_primary = 0usize;
+    for idx in 0..nc.num_circuits() {
+      let c = nc.primary_circuit(idx);
+      let z0 = vec![E1::Scalar::default(); c.arity()];
+      let advice0 = vec![E1::Scalar::default(); c.advice_size()];
+      let (_, cs) = synthesize(&c, &z0, &advice0)?;
+      let shape = cs.shape();
+      // folding commits to both `W` (num_vars entries) and `E`/`T` (num_cons entries)
+      max_vec_len_primary = max_vec_len_primary.max(shape.num_vars).max(shape.num_cons);
+      primary_r1cs_shapes.push(R1CSWithArity::new(shape, c.arity()));
+    }
+
+    let c2 = nc.secondary_circuit();
+    let z0_secondary = vec![E2::Scalar::default(); c2.arity()];
+    let advice0_secondary = vec![E2::Scalar::default(); c2.advice_size()];
+    let (_, cs_secondary) = synthesize(&c2, &z0_secondary, &advice0_secondary)?;
+    let secondary_shape = cs_secondary.shape();
+
+    let ck_primary = E1::CE::setup(b"supernova_ck_primary", max_vec_len_primary.max(1));
+    let ck_secondary = E2::CE::setup(
+      b"supernova_ck_secondary",
+      secondary_shape.num_vars.max(secondary_shape.num_cons).max(1),
+    );
+
+    Ok(Self {
+      primary_r1cs_shapes,
+      secondary_r1cs_shape: R1CSWithArity::new(secondary_shape, c2.arity()),
+      ck_primary,
+      ck_secondary,
+    })
+  }
+
+  /// The number of primary circuits this set of public parameters was derived for.
+  pub fn num_circuits(&self) -> usize {
+    self.primary_r1cs_shapes.len()
+  }
+}
+
+/// A SNARK that proves the correct execution of a non-uniform IVC, i.e. a sequence of steps each
+/// of which may run a different circuit selected by `pc`.
+///
+/// Unlike the uniform `crate::RecursiveSNARK`, which carries a single running accumulator,
+/// this carries one running relaxed-R1CS instance/witness pair *per primary circuit*. Only the
+/// pair indexed by the active `pc` is updated at each `prove_step`; the others are untouched.
+pub struct RecursiveSNARK<E1, E2>
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+{
+  // program counter selecting which primary accumulator was folded at the last completed step;
+  // `None` before the first step
+  pc: Option<usize>,
+  num_steps: usize,
+
+  // one running accumulator per primary circuit; entries for circuits not yet visited sit at
+  // their all-zero default
+  r_w_primary: Vec<RelaxedR1CSWitness<E1>>,
+  r_u_primary: Vec<RelaxedR1CSInstance<E1>>,
+
+  // single running accumulator for the (uniform) secondary circuit
+  r_w_secondary: RelaxedR1CSWitness<E2>,
+  r_u_secondary: RelaxedR1CSInstance<E2>,
+
+  z0_primary: Vec<E1::Scalar>,
+  z0_secondary: Vec<E2::Scalar>,
+  zi_primary: Vec<E1::Scalar>,
+  zi_secondary: Vec<E2::Scalar>,
+
+  transcript_primary: E1::TE,
+  transcript_secondary: E2::TE,
+}
+
+impl<E1, E2> RecursiveSNARK<E1, E2>
+where
+  E1: Engine<Base = <E2 as Engine>::Scalar>,
+  E2: Engine<Base = <E1 as Engine>::Scalar>,
+{
+  /// Starts a fresh non-uniform IVC chain at `(z0_primary, z0_secondary)`, with every primary
+  /// accumulator (one per circuit in `pp`) and the secondary accumulator at their all-zero
+  /// default.
+  pub fn new(
+    pp: &PublicParams<E1, E2>,
+    z0_primary: &[E1::Scalar],
+    z0_secondary: &[E2::Scalar],
+  ) -> Result<Self, NovaError> {
+    let r_u_primary = pp
+      .primary_r1cs_shapes
+      .iter()
+      .map(|s| RelaxedR1CSInstance::default(&pp.ck_primary, s.shape.num_io))
+      .collect();
+    let r_w_primary = pp
+      .primary_r1cs_shapes
+      .iter()
+      .map(|s| RelaxedR1CSWitness::default(s.shape.num_vars, s.shape.num_cons))
+      .collect();
+
+    Ok(Self {
+      pc: None,
+      num_steps: 0,
+      r_w_primary,
+      r_u_primary,
+      r_w_secondary: RelaxedR1CSWitness::default(
+        pp.secondary_r1cs_shape.shape.num_vars,
+        pp.secondary_r1cs_shape.shape.num_cons,
+      ),
+      r_u_secondary: RelaxedR1CSInstance::default(&pp.ck_secondary, pp.secondary_r1cs_shape.shape.num_io),
+      z0_primary: z0_primary.to_vec(),
+      z0_secondary: z0_secondary.to_vec(),
+      zi_primary: z0_primary.to_vec(),
+      zi_secondary: z0_secondary.to_vec(),
+      transcript_primary: E1::TE::new(b"supernova_recursive_snark_primary"),
+      transcript_secondary: E2::TE::new(b"supernova_recursive_snark_secondary"),
+    })
+  }
+
+  /// The program counter that selected the accumulator folded by the most recent `prove_step`.
+  pub fn program_counter(&self) -> Option<usize> {
+    self.pc
+  }
+
+  /// Number of completed recursive steps.
+  pub fn num_steps(&self) -> usize {
+    self.num_steps
+  }
+
+  /// Folds the instance produced by really synthesizing `pc`'s primary circuit into that
+  /// circuit's running accumulator, leaving every other primary accumulator unchanged, and folds
+  /// the secondary circuit's instance into the shared secondary accumulator as usual.
+  ///
+  /// `advice_primary`/`advice_secondary` carry this step's witness-only, non-deterministic
+  /// auxiliary input (see `StepCircuit::synthesize`); their lengths must match
+  /// `c_primary.advice_size()`/`c_secondary.advice_size()`.
+  ///
+  /// Returns `NovaError::InvalidStepCircuitIndex` if `pc` is not `< pp.num_circuits()`, or if the
+  /// circuit handed to this step reports a `circuit_index()` other than `pc`.
+  pub fn prove_step<C1, C2>(
+    &mut self,
+    pp: &PublicParams<E1, E2>,
+    pc: usize,
+    c_primary: &C1,
+    c_secondary: &C2,
+    advice_primary: &[E1::Scalar],
+    advice_secondary: &[E2::Scalar],
+  ) -> Result<(), NovaError>
+  where
+    C1: StepCircuit<E1::Scalar>,
+    C2: StepCircuit<E2::Scalar>,
+  {
+    if c_primary.circuit_index() != pc {
+      return Err(NovaError::InvalidStepCircuitIndex);
+    }
+    if pc >= self.r_u_primary.len() || pc >= pp.primary_r1cs_shapes.len() {
+      return Err(NovaError::InvalidStepCircuitIndex);
+    }
+
+    let (z_next_primary, cs_primary) = synthesize(c_primary, &self.zi_primary, advice_primary)?;
+    let (u_primary, w_primary) = cs_primary.instance_and_witness(&pp.ck_primary);
+    let (_nifs, (folded_u, folded_w)) = NIFS::prove(
+      &pp.ck_primary,
+      &pp.primary_r1cs_shapes[pc].shape,
+      &mut self.transcript_primary,
+      &self.r_u_primary[pc],
+      &self.r_w_primary[pc],
+      &u_primary,
+      &w_primary,
+    )?;
+    self.r_u_primary[pc] = folded_u;
+    self.r_w_primary[pc] = folded_w;
+
+    let (z_next_secondary, cs_secondary) =
+      synthesize(c_secondary, &self.zi_secondary, advice_secondary)?;
+    let (u_secondary, w_secondary) = cs_secondary.instance_and_witness(&pp.ck_secondary);
+    let (_nifs_secondary, (folded_u_secondary, folded_w_secondary)) = NIFS::prove(
+      &pp.ck_secondary,
+      &pp.secondary_r1cs_shape.shape,
+      &mut self.transcript_secondary,
+      &self.r_u_secondary,
+      &self.r_w_secondary,
+      &u_secondary,
+      &w_secondary,
+    )?;
+    self.r_u_secondary = folded_u_secondary;
+    self.r_w_secondary = folded_w_secondary;
+
+    self.zi_primary = z_next_primary;
+    self.zi_secondary = z_next_secondary;
+    self.pc = Some(pc);
+    self.num_steps += 1;
+    Ok(())
+  }
+
+  /// Verifies `num_steps` of non-uniform IVC, checking that the final program counter is in
+  /// range, that the chain actually started at the claimed `(z0_primary, z0_secondary)`, and
+  /// that every running accumulator (primary, per circuit, and secondary) still satisfies the
+  /// relaxed R1CS relation for its shape.
+  pub fn verify(
+    &self,
+    pp: &PublicParams<E1, E2>,
+    num_steps: usize,
+    z0_primary: &[E1::Scalar],
+    z0_secondary: &[E2::Scalar],
+  ) -> Result<(Vec<E1::Scalar>, Vec<E2::Scalar>), NovaError> {
+    if self.num_steps != num_steps {
+      return Err(NovaError::ProofVerifyError);
+    }
+    if self.z0_primary != z0_primary || self.z0_secondary != z0_secondary {
+      return Err(NovaError::ProofVerifyError);
+    }
+    let Some(pc) = self.pc else {
+      return Err(NovaError::InvalidStepCircuitIndex);
+    };
+    if pc >= pp.primary_r1cs_shapes.len() {
+      return Err(NovaError::InvalidStepCircuitIndex);
+    }
+
+    for (shape, (u, w)) in pp
+      .primary_r1cs_shapes
+      .iter()
+      .zip(self.r_u_primary.iter().zip(self.r_w_primary.iter()))
+    {
+      shape.shape.is_sat_relaxed(&pp.ck_primary, u, w)?;
+    }
+    pp.secondary_r1cs_shape
+      .shape
+      .is_sat_relaxed(&pp.ck_secondary, &self.r_u_secondary, &self.r_w_secondary)?;
+
+    Ok((self.zi_primary.clone(), self.zi_secondary.clone()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils::ScalarOnlyEngine;
+  use bellpepper_core::test_cs::TestConstraintSystem;
+  use pasta_curves::Fp;
+
+  // a minimal two-way non-uniform circuit for exercising `NonUniformCircuit`: both `pc = 0` and
+  // `pc = 1` run a trivial passthrough on the primary side; `pc = 1` shows that a circuit
+  // reporting a non-zero `circuit_index()` is expressible.
+  #[derive(Clone, Debug)]
+  struct TwoWayCircuit<F: ff::PrimeField> {
+    index: usize,
+    _p: core::marker::PhantomData<F>,
+  }
+
+  impl<F: ff::PrimeField> StepCircuit<F> for TwoWayCircuit<F> {
+    fn arity(&self) -> usize {
+      1
+    }
+
+    fn circuit_index(&self) -> usize {
+      self.index
+    }
+
+    fn synthesize<CS: ConstraintSystem<F>>(
+      &self,
+      _cs: &mut CS,
+      z: &[AllocatedNum<F>],
+      _advice: &[AllocatedNum<F>],
+    ) -> Result<Vec<AllocatedNum<F>>, SynthesisError> {
+      Ok(z.to_vec())
+    }
+  }
+
+  // `StepCircuit` is generic only over `PrimeField`, not over a full `Engine`/`DlogGroup`/
+  // commitment backend -- which, as elsewhere in this crate's test suite (see `gadgets::bits`),
+  // lets a step circuit be exercised directly against a concrete field with no curve-group
+  // plumbing required.
+  #[test]
+  fn two_way_circuit_is_a_satisfied_passthrough_at_either_index() {
+    for index in [0usize, 1usize] {
+      let circuit = TwoWayCircuit::<Fp> {
+        index,
+        _p: core::marker::PhantomData,
+      };
+      let mut cs = TestConstraintSystem::<Fp>::new();
+      let z = vec![AllocatedNum::alloc(cs.namespace(|| "z0"), || Ok(Fp::from(7u64))).unwrap()];
+      let z_out = circuit.synthesize(&mut cs, &z, &[]).unwrap();
+
+      assert!(cs.is_satisfied());
+      assert_eq!(z_out.len(), 1);
+      assert_eq!(z_out[0].get_value().unwrap(), Fp::from(7u64));
+      assert_eq!(circuit.circuit_index(), index);
+    }
+  }
+
+  #[test]
+  fn advice_length_mismatch_is_rejected_before_synthesis() {
+    struct WantsAdvice;
+    impl StepCircuit<Fp> for WantsAdvice {
+      fn arity(&self) -> usize {
+        1
+      }
+      fn advice_size(&self) -> usize {
+        1
+      }
+      fn synthesize<CS: ConstraintSystem<Fp>>(
+        &self,
+        _cs: &mut CS,
+        z: &[AllocatedNum<Fp>],
+        _advice: &[AllocatedNum<Fp>],
+      ) -> Result<Vec<AllocatedNum<Fp>>, SynthesisError> {
+        Ok(z.to_vec())
+      }
+    }
+
+    // exercising `prove_step`/`verify` end to end additionally needs a concrete `DlogGroup`
+    // implementation to build a commitment key, which -- like the rest of this crate's curve
+    // backends -- lives outside this snapshot; `synthesize()`'s own input validation is real and
+    // independent of that, so it's what's covered here.
+    let err = synthesize::<ScalarOnlyEngine, WantsAdvice>(&WantsAdvice, &[Fp::from(1u64)], &[]);
+    assert!(matches!(err, Err(NovaError::InvalidWitnessLength)));
+  }
+}
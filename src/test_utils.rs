@@ -0,0 +1,49 @@
+//! A minimal `Engine` shared by this crate's unit tests that need a concrete `Engine` type to
+//! call functions generic over one, but only ever exercise that `Engine`'s scalar-field logic
+//! (sum-check, transcript absorption, dense-matrix folding, and the like). Its fields are `Fp`, its
+//! commitment and transcript engines are `pedersen`'s and `keccak`'s, and every group operation
+//! (`group_params` and the `DlogGroup` methods) is `unimplemented!()` since no concrete
+//! curve/pairing backend exists in this tree to back one.
+#![cfg(test)]
+
+use crate::provider::traits::DlogGroup;
+use crate::traits::{Engine, Group};
+use pasta_curves::Fp;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct ScalarOnlyEngine;
+
+impl Group for ScalarOnlyEngine {
+  type Base = Fp;
+  type Scalar = Fp;
+  fn group_params() -> (Self::Base, Self::Base, num_bigint::BigInt) {
+    unimplemented!("not exercised by tests using ScalarOnlyEngine")
+  }
+}
+
+impl DlogGroup for ScalarOnlyEngine {
+  type AffineGroupElement = ();
+  fn vartime_multiscalar_mul(
+    _scalars: &[Self::Scalar],
+    _bases: &[Self::AffineGroupElement],
+  ) -> Self {
+    unimplemented!("not exercised by tests using ScalarOnlyEngine")
+  }
+  fn to_affine(&self) -> Self::AffineGroupElement {
+    unimplemented!("not exercised by tests using ScalarOnlyEngine")
+  }
+  fn generator() -> Self::AffineGroupElement {
+    unimplemented!("not exercised by tests using ScalarOnlyEngine")
+  }
+  fn from_label(_label: &'static [u8], _n: usize) -> Vec<Self::AffineGroupElement> {
+    unimplemented!("not exercised by tests using ScalarOnlyEngine")
+  }
+}
+
+impl Engine for ScalarOnlyEngine {
+  type Base = Fp;
+  type Scalar = Fp;
+  type GE = Self;
+  type CE = crate::provider::pedersen::CommitmentEngine<Self>;
+  type TE = crate::provider::keccak::Keccak256Transcript<Self>;
+}
@@ -0,0 +1,66 @@
+//! This module defines the `StepCircuit` trait, which is the primary way in which a user of
+//! Nova can express the computation they wish to prove incrementally.
+use bellpepper_core::{num::AllocatedNum, ConstraintSystem, SynthesisError};
+use core::marker::PhantomData;
+use ff::PrimeField;
+use serde::{Deserialize, Serialize};
+
+/// A helper trait for a step of an incremental computation (i.e., circuits for `F` in an IVC
+/// scheme).
+pub trait StepCircuit<F: PrimeField>: Send + Sync + Clone {
+  /// Return the number of inputs or outputs of each step. The arity is used to allocate the
+  /// right number of variables for `z_i` and `z_{i+1}`.
+  fn arity(&self) -> usize;
+
+  /// Returns the index, among the circuits making up a non-uniform IVC, that this instance
+  /// implements. A uniform IVC has exactly one circuit, so the default implementation returns
+  /// `0`; `NonUniformCircuit` implementors select among several `StepCircuit`s by returning a
+  /// distinct index from each.
+  fn circuit_index(&self) -> usize {
+    0
+  }
+
+  /// The number of field elements of non-deterministic advice this circuit consumes per step,
+  /// i.e. the length `synthesize` expects `advice` to have. Circuits that need no advice (like
+  /// `TrivialCircuit`) return `0`.
+  fn advice_size(&self) -> usize {
+    0
+  }
+
+  /// Synthesize the circuit for a computation step and return variables representing the
+  /// output of the step `z_{i+1}`.
+  ///
+  /// `advice` carries witness-only, non-deterministic auxiliary input supplied by the prover at
+  /// `RecursiveSNARK::prove_step` time (e.g. a hard-to-compute value the circuit only verifies,
+  /// such as MinRoot's fifth roots). Unlike `z`, `advice` is never folded into the running
+  /// public IO; a circuit that ignores it is free to leave `advice` empty by returning `0` from
+  /// `advice_size`.
+  fn synthesize<CS: ConstraintSystem<F>>(
+    &self,
+    cs: &mut CS,
+    z: &[AllocatedNum<F>],
+    advice: &[AllocatedNum<F>],
+  ) -> Result<Vec<AllocatedNum<F>>, SynthesisError>;
+}
+
+/// A trivial step circuit that simply returns the input, used for the secondary circuit of the
+/// recursive SNARK when the caller has no computation to run on that side of the cycle.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TrivialCircuit<F: PrimeField> {
+  _p: PhantomData<F>,
+}
+
+impl<F: PrimeField> StepCircuit<F> for TrivialCircuit<F> {
+  fn arity(&self) -> usize {
+    1
+  }
+
+  fn synthesize<CS: ConstraintSystem<F>>(
+    &self,
+    _cs: &mut CS,
+    z: &[AllocatedNum<F>],
+    _advice: &[AllocatedNum<F>],
+  ) -> Result<Vec<AllocatedNum<F>>, SynthesisError> {
+    Ok(z.to_vec())
+  }
+}
@@ -0,0 +1,42 @@
+//! Commitment scheme traits used to commit to witness vectors (R1CS) and, by the evaluation
+//! engines in `provider`, to polynomials.
+use crate::traits::{transcript::TranscriptReprTrait, Engine};
+use core::fmt::Debug;
+use core::ops::Add;
+use serde::{Deserialize, Serialize};
+
+/// A commitment produced by a `CommitmentEngineTrait`. Folding (`NIFS`) relies on commitments
+/// being additively homomorphic: `Commit(v1) + Commit(v2) == Commit(v1 + v2)`, and similarly for
+/// scalar multiplication, so a linear combination of committed vectors can be verified from the
+/// commitments alone, without ever decommitting.
+pub trait CommitmentTrait<E: Engine>:
+  Clone
+  + Debug
+  + PartialEq
+  + Eq
+  + Send
+  + Sync
+  + Serialize
+  + for<'de> Deserialize<'de>
+  + Add<Self, Output = Self>
+  + TranscriptReprTrait<E>
+{
+  /// Returns `self * scalar`.
+  fn scalar_mul(&self, scalar: &E::Scalar) -> Self;
+}
+
+/// A vector commitment scheme: commits to a vector of scalars with a single, shorter
+/// `Commitment`, homomorphically in the vector.
+pub trait CommitmentEngineTrait<E: Engine>: Clone + Debug + Send + Sync {
+  /// Public parameters (e.g. a set of generators) sized to commit to vectors up to some length.
+  type CommitmentKey: Clone + Debug + Send + Sync + Serialize + for<'de> Deserialize<'de>;
+  /// The output of `commit`.
+  type Commitment: CommitmentTrait<E>;
+
+  /// Derives a commitment key able to commit to vectors of length up to `n`, deterministically
+  /// from `label` (no trusted setup).
+  fn setup(label: &'static [u8], n: usize) -> Self::CommitmentKey;
+
+  /// Commits to `v`. `ck` must support vectors at least as long as `v`.
+  fn commit(ck: &Self::CommitmentKey, v: &[E::Scalar]) -> Self::Commitment;
+}
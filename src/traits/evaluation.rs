@@ -0,0 +1,48 @@
+//! The polynomial evaluation engine trait: commits to a multilinear polynomial and later proves
+//! (succinctly, with respect to the polynomial's size) that it evaluates to a claimed value at a
+//! claimed point. Implemented in this tree by `provider::hyperkzg::EvaluationEngine`
+//! (pairing-friendly groups only; proofs logarithmic in the polynomial's length).
+use crate::{
+  errors::NovaError,
+  traits::{commitment::CommitmentEngineTrait, Engine, TranscriptEngineTrait},
+};
+use serde::{Deserialize, Serialize};
+
+pub trait EvaluationEngineTrait<E: Engine>: Clone + Send + Sync {
+  /// Prover-side setup output: whatever the scheme needs beyond the commitment key itself (e.g.
+  /// HyperKZG's powers-of-tau in `G1`).
+  type ProverKey: Clone + Send + Sync + Serialize + for<'de> Deserialize<'de>;
+  /// Verifier-side setup output, kept small since it's read on every verification.
+  type VerifierKey: Clone + Send + Sync + Serialize + for<'de> Deserialize<'de>;
+  /// The proof produced by `prove` and checked by `verify`.
+  type EvaluationArgument: Clone + Send + Sync + Serialize + for<'de> Deserialize<'de>;
+
+  /// Derives `(pk, vk)` from a commitment key already sized for the polynomials this engine will
+  /// be asked to open.
+  fn setup(
+    ck: &<E::CE as CommitmentEngineTrait<E>>::CommitmentKey,
+  ) -> (Self::ProverKey, Self::VerifierKey);
+
+  /// Proves that `poly` (the coefficients of the multilinear polynomial committed to in `comm`)
+  /// evaluates to `eval` at `point`.
+  fn prove(
+    ck: &<E::CE as CommitmentEngineTrait<E>>::CommitmentKey,
+    pk: &Self::ProverKey,
+    transcript: &mut E::TE,
+    comm: &<E::CE as CommitmentEngineTrait<E>>::Commitment,
+    poly: &[E::Scalar],
+    point: &[E::Scalar],
+    eval: &E::Scalar,
+  ) -> Result<Self::EvaluationArgument, NovaError>;
+
+  /// Verifies `arg` as a proof that the polynomial committed to in `comm` evaluates to `eval` at
+  /// `point`.
+  fn verify(
+    vk: &Self::VerifierKey,
+    transcript: &mut E::TE,
+    comm: &<E::CE as CommitmentEngineTrait<E>>::Commitment,
+    point: &[E::Scalar],
+    eval: &E::Scalar,
+    arg: &Self::EvaluationArgument,
+  ) -> Result<(), NovaError>;
+}
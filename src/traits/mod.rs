@@ -0,0 +1,38 @@
+//! Core traits of the library: the curve-cycle abstractions (`Group`, `Engine`) that every other
+//! module is generic over, plus the pluggable-backend traits (`circuit`, `commitment`,
+//! `evaluation`, `snark`, `transcript`) implemented in `provider` and `spartan`.
+pub mod circuit;
+pub mod commitment;
+pub mod evaluation;
+pub mod snark;
+pub mod transcript;
+
+pub use transcript::{TranscriptEngineTrait, TranscriptReprTrait};
+
+use commitment::CommitmentEngineTrait;
+use core::fmt::Debug;
+use ff::{PrimeField, PrimeFieldBits};
+use num_bigint::BigInt;
+use serde::{Deserialize, Serialize};
+
+/// A curve's scalar field, together with the handful of curve-specific parameters `MinRootIteration`
+/// needs to compute fifth roots (the curve equation's coefficients, and the scalar field's order
+/// as a `BigInt` so the `(p-3)/5` exponent can be computed generically).
+pub trait Group: Clone + Copy + Debug + Eq + Send + Sync {
+  type Base: PrimeField;
+  type Scalar: PrimeField + PrimeFieldBits + Serialize + for<'de> Deserialize<'de>;
+
+  /// Returns `(a, b, order)` for the short Weierstrass curve `y^2 = x^3 + a*x + b` over `Base`,
+  /// where `order` is the order of the scalar field.
+  fn group_params() -> (Self::Base, Self::Base, BigInt);
+}
+
+/// One side of a 2-cycle of curves: `Engine::Base` equals the scalar field of the other side,
+/// which is what lets that side's scalar arithmetic be expressed natively in this side's circuit.
+pub trait Engine: Clone + Copy + Debug + Eq + Send + Sync + Sized + 'static {
+  type Base: PrimeField;
+  type Scalar: PrimeField + PrimeFieldBits + Serialize + for<'de> Deserialize<'de>;
+  type GE: Group<Base = Self::Base, Scalar = Self::Scalar>;
+  type CE: CommitmentEngineTrait<Self>;
+  type TE: TranscriptEngineTrait<Self>;
+}
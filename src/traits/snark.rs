@@ -0,0 +1,34 @@
+//! The final-compression SNARK trait: proves, given a relaxed R1CS instance/witness pair, that
+//! the witness satisfies the relation, in a way that can be verified without the witness.
+//! Implemented in this tree by `spartan::ppsnark::RelaxedR1CSSNARK`, a preprocessing SNARK whose
+//! verifier time grows only logarithmically with `num_cons`.
+use crate::{
+  errors::NovaError,
+  r1cs::{R1CSShape, RelaxedR1CSInstance, RelaxedR1CSWitness},
+  CommitmentKey,
+};
+use serde::Serialize;
+
+pub trait RelaxedR1CSSNARKTrait<E: crate::traits::Engine>: Clone + Send + Sync + Serialize {
+  type ProverKey: Clone + Send + Sync;
+  type VerifierKey: Clone + Send + Sync + Serialize;
+
+  /// The minimum size commitment key this SNARK needs for a given shape -- non-preprocessing
+  /// SNARKs only ever commit to witness vectors so this is just `shape.num_vars`, but a
+  /// preprocessing SNARK that additionally commits to the matrices themselves needs more.
+  fn ck_floor() -> Box<dyn for<'a> Fn(&'a R1CSShape<E>) -> usize>;
+
+  fn setup(
+    ck: &CommitmentKey<E>,
+    S: &R1CSShape<E>,
+  ) -> Result<(Self::ProverKey, Self::VerifierKey), NovaError>;
+
+  fn prove(
+    ck: &CommitmentKey<E>,
+    pk: &Self::ProverKey,
+    U: &RelaxedR1CSInstance<E>,
+    W: &RelaxedR1CSWitness<E>,
+  ) -> Result<Self, NovaError>;
+
+  fn verify(&self, vk: &Self::VerifierKey, U: &RelaxedR1CSInstance<E>) -> Result<(), NovaError>;
+}
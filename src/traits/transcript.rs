@@ -0,0 +1,33 @@
+//! Fiat-Shamir transcript traits: turn an interactive protocol into a non-interactive one by
+//! deriving the verifier's random challenges from a running hash of everything absorbed so far.
+use crate::{errors::NovaError, traits::Engine};
+use ff::PrimeField;
+
+/// Implemented by anything that can be absorbed into a `TranscriptEngineTrait`, by serializing
+/// the field elements/commitments it's made of into bytes in a fixed, public order.
+pub trait TranscriptReprTrait<E: Engine>: Send + Sync {
+  /// A canonical byte encoding used only for Fiat-Shamir absorption; this is not required to be
+  /// a full serialization of `self`; it only needs to be injective over the values that can
+  /// actually occur in a transcript.
+  fn to_transcript_bytes(&self) -> Vec<u8>;
+}
+
+impl<E: Engine> TranscriptReprTrait<E> for E::Scalar {
+  fn to_transcript_bytes(&self) -> Vec<u8> {
+    self.to_repr().as_ref().to_vec()
+  }
+}
+
+/// A Fiat-Shamir transcript: the prover and verifier run the identical sequence of `absorb`/
+/// `squeeze` calls, so they derive identical challenges without interacting.
+pub trait TranscriptEngineTrait<E: Engine>: Send + Sync + Clone {
+  /// Starts a new transcript, domain-separated by `label`.
+  fn new(label: &'static [u8]) -> Self;
+
+  /// Folds `o` into the transcript's running state, domain-separated by `label`.
+  fn absorb<T: TranscriptReprTrait<E>>(&mut self, label: &'static [u8], o: &T);
+
+  /// Derives the next challenge scalar, domain-separated by `label`, and folds it back into the
+  /// running state so repeated calls produce distinct challenges.
+  fn squeeze(&mut self, label: &'static [u8]) -> Result<E::Scalar, NovaError>;
+}